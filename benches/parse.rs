@@ -0,0 +1,104 @@
+// Number parsing on its own: the str::parse code the days used before next to crate::common::parse,
+// on generated inputs. Run with `cargo bench --bench parse`
+use std::hint::black_box;
+
+use advent_of_code::bench::{self, Config};
+use advent_of_code::common::parse::{self, Cursor, ParseError, Separator};
+use advent_of_code::generators;
+
+const SEED: u64 = 2025;
+
+fn report(group: &str, bytes: usize, cases: &[(&str, &dyn Fn() -> u64)]) {
+    let config = Config::default();
+    println!("{} ({} bytes)", group, bytes);
+
+    let mut baseline = None;
+    for (name, case) in cases {
+        let median = bench::measure(&config, || black_box(case())).median();
+        let base = *baseline.get_or_insert(median);
+        println!(
+            "  {:<16} median {:>12?}  {:>6.2}x",
+            name,
+            median,
+            base.as_secs_f64() / median.as_secs_f64()
+        );
+    }
+}
+
+fn point(cursor: &mut Cursor) -> Result<[u64; 3], ParseError> {
+    let x = cursor.unsigned()?;
+    cursor.expect(b',')?;
+    let y = cursor.unsigned()?;
+    cursor.expect(b',')?;
+    Ok([x, y, cursor.unsigned()?])
+}
+
+fn main() {
+    // Day 5 at 10x the usual size: a-b ranges, a blank line, then ids
+    let elf_db = generators::generate(2025, 5, 2000, SEED).unwrap();
+    let (ranges, ids) = elf_db.split_once("\n\n").unwrap();
+
+    report(
+        "ids",
+        ids.len(),
+        &[
+            ("str::parse", &|| {
+                ids.lines().map(|s| s.parse::<u64>().unwrap()).sum()
+            }),
+            ("parse::list", &|| {
+                parse::list::<u64>(ids.as_bytes(), Separator::Whitespace)
+                    .map(Result::unwrap)
+                    .sum()
+            }),
+        ],
+    );
+
+    report(
+        "ranges",
+        ranges.len(),
+        &[
+            ("str::parse", &|| {
+                ranges
+                    .lines()
+                    .map(|l| {
+                        let (a, b) = l.split_once('-').unwrap();
+                        a.parse::<u64>().unwrap() ^ b.parse::<u64>().unwrap()
+                    })
+                    .sum()
+            }),
+            ("parse::ranges", &|| {
+                parse::ranges::<u64>(ranges.as_bytes(), Separator::Whitespace)
+                    .map(|r| {
+                        let r = r.unwrap();
+                        r.start() ^ r.end()
+                    })
+                    .sum()
+            }),
+        ],
+    );
+
+    let points = generators::generate(2025, 8, 10_000, SEED).unwrap();
+    report(
+        "points",
+        points.len(),
+        &[
+            ("str::parse", &|| {
+                points
+                    .lines()
+                    .map(|l| {
+                        let p: Vec<u64> = l.split(',').map(|s| s.parse().unwrap()).collect();
+                        p[0] ^ p[1] ^ p[2]
+                    })
+                    .sum()
+            }),
+            ("parse::separated", &|| {
+                parse::separated(points.as_bytes(), Separator::Whitespace, point)
+                    .map(|p| {
+                        let [x, y, z] = p.unwrap();
+                        x ^ y ^ z
+                    })
+                    .sum()
+            }),
+        ],
+    );
+}
@@ -0,0 +1,47 @@
+// Benchmarks every registered solver variant on generated inputs
+// Run with `cargo bench`, or `cargo bench -- day4` to only run groups whose name contains "day4"
+use advent_of_code::bench::{self, Config};
+use advent_of_code::{generators, registry};
+
+// Fixed so results stay comparable between runs
+const SEED: u64 = 2025;
+
+fn main() {
+    // cargo passes --bench (and friends), anything else is a name filter
+    let filters: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|a| !a.starts_with("--"))
+        .collect();
+
+    let config = Config::default();
+
+    for (year, day) in registry::years().flat_map(|y| registry::days(y).map(move |d| (y, d))) {
+        let size = generators::default_size(year, day).expect("no generator for day");
+        let input = generators::generate(year, day, size, SEED).expect("no generator for day");
+
+        for part in 1..=2 {
+            let group = format!("{}/day{}/p{}", year, day, part);
+            let selected: Vec<_> = registry::variants(year, day, part)
+                .filter(|s| filters.is_empty() || filters.iter().any(|f| s.name().contains(f)))
+                .collect();
+
+            if selected.is_empty() {
+                continue;
+            }
+
+            println!("{} ({} bytes of input)", group, input.len());
+
+            for solver in selected {
+                let stats = bench::measure(&config, || (solver.solve)(&input));
+                println!(
+                    "  {:<12} median {:>12?}  min {:>12?}  mean {:>12?}  ({} samples)",
+                    solver.variant,
+                    stats.median(),
+                    stats.min(),
+                    stats.mean(),
+                    stats.len()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// Generated from src/ffi.rs by cbindgen (see cbindgen.toml), dont edit by hand.
+// See src/ffi.rs for how answers and errors are returned. Link against
+// libadvent_of_code (cargo build --release --lib)
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// No solver registered for that year/day/part/variant
+#define AOC_ERR_NO_SOLVER -1
+
+// Null pointer, or input/variant that isnt UTF-8
+#define AOC_ERR_INVALID_ARGUMENT -2
+
+// The solver panicked, usually on malformed input
+#define AOC_ERR_SOLVER_FAILED -3
+
+// Solves a part of the latest year with its baseline variant
+//
+// # Safety
+// `input` must point to `len` readable bytes and `out` to `out_len` writable bytes (`out` may be
+// null if `out_len` is 0)
+int64_t aoc_solve(uint8_t day,
+                  uint8_t part,
+                  const uint8_t *input,
+                  size_t len,
+                  char *out,
+                  size_t out_len);
+
+// Same as aoc_solve for any year and variant, `variant` is a NUL terminated name or null for the
+// baseline
+//
+// # Safety
+// Same as aoc_solve, and `variant` must be null or a valid NUL terminated string
+int64_t aoc_solve_variant(uint16_t year,
+                          uint8_t day,
+                          uint8_t part,
+                          const char *variant,
+                          const uint8_t *input,
+                          size_t len,
+                          char *out,
+                          size_t out_len);
+
+// Number of registered solvers, for listing them with aoc_solver_name
+size_t aoc_solver_count(void);
+
+// Writes the name of solver `index` ("2025/day3/p2/fast") into `out`, returns like aoc_solve
+//
+// # Safety
+// `out` must point to `out_len` writable bytes (or be null if `out_len` is 0)
+int64_t aoc_solver_name(size_t index, char *out, size_t out_len);
+
+#endif  /* AOC_H */
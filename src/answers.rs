@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+// Known good answers, one `<input sha256> <year> <day> <part> <answer>` per line. Keying on the
+// input hash lets answers for several accounts' inputs live side by side, and says exactly which
+// file an answer belongs to. Lines starting with # are comments
+#[derive(Default)]
+pub struct Answers {
+    entries: BTreeMap<(String, u16, u8, u8), String>,
+}
+
+impl Answers {
+    // A missing file is just an empty set of answers
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Answers> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(e) => return Err(e),
+        };
+
+        let mut entries = BTreeMap::new();
+        for (n, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || {
+                io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "answers line {}: expected <input sha256> <year> <day> <part> <answer>",
+                        n + 1
+                    ),
+                )
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [sha, year, day, part, answer] = fields[..] else {
+                return Err(invalid());
+            };
+
+            if sha.len() != 64 || !sha.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(invalid());
+            }
+            let (Ok(year), Ok(day), Ok(part)) = (year.parse(), day.parse(), part.parse()) else {
+                return Err(invalid());
+            };
+
+            entries.insert((sha.to_lowercase(), year, day, part), answer.to_string());
+        }
+
+        Ok(Answers { entries })
+    }
+
+    pub fn get(&self, sha: &str, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.entries
+            .get(&(sha.to_string(), year, day, part))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, sha: &str, year: u16, day: u8, part: u8, answer: String) {
+        self.entries
+            .insert((sha.to_string(), year, day, part), answer);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u16, u8, u8, &str)> {
+        self.entries.iter().map(|((sha, year, day, part), answer)| {
+            (sha.as_str(), *year, *day, *part, answer.as_str())
+        })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut out = String::from("# <input sha256> <year> <day> <part> <answer>\n");
+        for (sha, year, day, part, answer) in self.iter() {
+            out.push_str(&format!("{} {} {} {} {}\n", sha, year, day, part, answer));
+        }
+        fs::write(path, out)
+    }
+}
@@ -0,0 +1,71 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// How long to keep sampling a single solver. We stop at whichever limit is hit first, but always
+// take at least min_samples so slow solvers still get a usable median
+pub struct Config {
+    pub budget: Duration,
+    pub min_samples: usize,
+    pub max_samples: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            budget: Duration::from_secs(1),
+            min_samples: 5,
+            max_samples: 1000,
+        }
+    }
+}
+
+pub struct Stats {
+    // Sorted ascending so the percentiles are just lookups
+    samples: Vec<Duration>,
+}
+
+impl Stats {
+    pub fn median(&self) -> Duration {
+        self.samples[self.samples.len() / 2]
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+
+    pub fn mean(&self) -> Duration {
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+// Times repeated calls of f. One untimed warmup call is made first so page faults and lazy
+// allocations dont end up in the first sample
+pub fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> Stats {
+    black_box(f());
+
+    let mut samples = Vec::with_capacity(config.min_samples);
+    let start = Instant::now();
+
+    while samples.len() < config.max_samples
+        && (samples.len() < config.min_samples || start.elapsed() < config.budget)
+    {
+        let sample_start = Instant::now();
+        black_box(f());
+        samples.push(sample_start.elapsed());
+    }
+
+    samples.sort_unstable();
+    Stats { samples }
+}
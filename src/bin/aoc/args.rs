@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::str::FromStr;
+
+use advent_of_code::registry;
+
+// Minimal argument parser shared by every subcommand. Anything starting with -- is an option,
+// options listed in `valued` consume the next argument as their value, the rest are switches.
+// Short flags (-v) are global and handled before a subcommand runs
+pub struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, Option<String>>,
+}
+
+impl Args {
+    pub fn parse(raw: &[String], valued: &[&str]) -> Result<Args, Box<dyn Error>> {
+        let mut positional = Vec::new();
+        let mut options = HashMap::new();
+        let mut iter = raw.iter();
+
+        while let Some(arg) = iter.next() {
+            match arg.strip_prefix("--") {
+                Some(name) if valued.contains(&name) => {
+                    let value = iter
+                        .next()
+                        .ok_or_else(|| format!("--{} needs a value", name))?;
+                    options.insert(name.to_string(), Some(value.clone()));
+                }
+                Some(name) => {
+                    options.insert(name.to_string(), None);
+                }
+                // A lone - is a positional, it stands for stdin
+                None if arg.starts_with('-') && arg != "-" => {}
+                None => positional.push(arg.clone()),
+            }
+        }
+
+        Ok(Args {
+            positional,
+            options,
+        })
+    }
+
+    pub fn positional<T: FromStr>(&self, idx: usize, name: &str) -> Result<T, Box<dyn Error>> {
+        let raw = self
+            .positional
+            .get(idx)
+            .ok_or_else(|| format!("missing <{}>", name))?;
+        raw.parse()
+            .map_err(|_| format!("invalid <{}>: {}", name, raw).into())
+    }
+
+    // Puzzles are addressed as `[year] <day>`, the year defaulting to the latest one with
+    // solutions. Returns the year, the day and the index of the next positional
+    pub fn puzzle(&self) -> Result<(u16, u8, usize), Box<dyn Error>> {
+        let first: u16 = self.positional(0, "day")?;
+
+        // Days only go up to 25 and the event started in 2015, so theres no ambiguity
+        if first >= 2015 {
+            Ok((first, self.positional(1, "day")?, 2))
+        } else {
+            let day = u8::try_from(first).map_err(|_| format!("invalid <day>: {}", first))?;
+            Ok((registry::latest_year(), day, 1))
+        }
+    }
+
+    pub fn value<T: FromStr>(&self, name: &str) -> Result<Option<T>, Box<dyn Error>> {
+        match self.options.get(name) {
+            Some(Some(raw)) => raw
+                .parse()
+                .map(Some)
+                .map_err(|_| format!("invalid --{}: {}", name, raw).into()),
+            _ => Ok(None),
+        }
+    }
+
+    // --times for the subcommands that average over repeated runs, 1 if not given. 0 runs would
+    // have nothing to average
+    pub fn times(&self) -> Result<u32, Box<dyn Error>> {
+        match self.value("times")? {
+            Some(0) => Err("--times has to be at least 1".into()),
+            times => Ok(times.unwrap_or(1)),
+        }
+    }
+
+    pub fn switch(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+}
@@ -0,0 +1,127 @@
+use std::error::Error;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use advent_of_code::answers::Answers;
+use advent_of_code::input::{self, Normalize};
+use advent_of_code::registry;
+
+use crate::args::Args;
+use crate::data;
+
+// aoc batch [year] <day> <dir> [--variant <name>] [--answers <file>] [--data <dir>] [--no-cache]
+// Runs a day against every file in a directory. Answers are checked against --answers, or
+// <dir>/answers.txt, or the usual data/answers.txt, whichever is found first
+pub fn main(raw: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(raw, &["variant", "answers", "data"])?;
+    let (year, day, next) = args.puzzle()?;
+    let dir: PathBuf = args.positional(next, "dir")?;
+    let variant: Option<String> = args.value("variant")?;
+    let mut cache = data::open_cache(&args)?;
+
+    let answers_path = match args.value::<PathBuf>("answers")? {
+        Some(path) => Some(path),
+        None => [
+            data::answers_path(&dir),
+            data::answers_path(&data::data_dir(&args)?),
+        ]
+        .into_iter()
+        .find(|p| p.exists()),
+    };
+    let answers = match &answers_path {
+        Some(path) => Answers::load(path)?,
+        None => Answers::default(),
+    };
+
+    let solvers = (1..=2)
+        .map(|part| registry::find(year, day, part, variant.as_deref()))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| format!("no solver for {} day {} with that variant", year, day))?;
+
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+    files.retain(|p| p.is_file() && Some(p.as_path()) != answers_path.as_deref());
+    files.sort();
+
+    // Solvers panic on inputs they cant handle, keep the message for the table instead of
+    // letting the default hook print a backtrace in the middle of it
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let (mut errors, mut mismatches) = (0, 0);
+
+    println!(
+        "{:<24} {:<5} {:<24} {:>14}  {:<18} input sha256",
+        "file", "part", "answer", "time", "check"
+    );
+
+    for path in &files {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+
+        let contents = match input::read(path, Normalize::from_flags(raw)) {
+            Ok(contents) => contents,
+            Err(e) => {
+                errors += 1;
+                println!("{:<24} {:<5} error: {}", name, "-", e);
+                continue;
+            }
+        };
+        let sha = input::sha256(contents.as_bytes());
+
+        for solver in &solvers {
+            let result =
+                panic::catch_unwind(AssertUnwindSafe(|| cache.solve(solver, &contents, &sha)));
+
+            let (answer, time, check) = match result {
+                Ok(outcome) => {
+                    let check = match answers.get(&sha, year, day, solver.part) {
+                        Some(expected) if expected == outcome.answer => "ok".to_string(),
+                        Some(expected) => {
+                            mismatches += 1;
+                            format!("FAIL ({})", expected)
+                        }
+                        None => "-".to_string(),
+                    };
+                    let time =
+                        format!("{:?}", outcome.time) + if outcome.cached { " (c)" } else { "" };
+                    (outcome.answer, time, check)
+                }
+                Err(payload) => {
+                    errors += 1;
+                    let message = payload
+                        .downcast_ref::<&str>()
+                        .map(|s| s.to_string())
+                        .or_else(|| payload.downcast_ref::<String>().cloned())
+                        .unwrap_or_else(|| "unknown panic".to_string());
+                    (
+                        format!("error: {}", message),
+                        "-".to_string(),
+                        "-".to_string(),
+                    )
+                }
+            };
+
+            println!(
+                "{:<24} {:<5} {:<24} {:>14}  {:<18} {}",
+                name, solver.part, answer, time, check, sha
+            );
+        }
+    }
+
+    panic::set_hook(default_hook);
+    cache.save()?;
+
+    println!(
+        "\n{} files, {} errors, {} wrong answers",
+        files.len(),
+        errors,
+        mismatches
+    );
+
+    if errors + mismatches > 0 {
+        return Err("batch had failures".into());
+    }
+    Ok(())
+}
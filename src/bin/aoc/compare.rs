@@ -0,0 +1,112 @@
+use std::error::Error;
+use std::time::Duration;
+
+use advent_of_code::bench::{self, Config};
+use advent_of_code::input::{self, Normalize};
+use advent_of_code::{counting_alloc, registry};
+
+use crate::args::Args;
+use crate::isolate::{self, Limits, Measure, Outcome};
+
+// aoc compare [year] <day> <input> [--part <1|2>] [--budget <ms>]
+//             [--isolate] [--max-mem <MiB>] [--max-cpu <s>] [--raw] [--keep-trailing]
+// Runs every variant of a part on the same input and reports how each one compares to the
+// baseline (the first variant registered for that part). Isolated variants run in their own
+// process so one blowing its limits doesnt take down the rest, but allocations arent counted
+pub fn main(raw: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(raw, &["part", "budget", "max-mem", "max-cpu"])?;
+    let (year, day, next) = args.puzzle()?;
+    let path: String = args.positional(next, "input")?;
+
+    let limits = Limits::from_args(&args)?;
+    let mut config = Config::default();
+    if let Some(ms) = args.value("budget")? {
+        config.budget = Duration::from_millis(ms);
+    }
+
+    let parts = match args.value("part")? {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let contents = input::read(path, Normalize::from_flags(raw))?;
+    let sha = input::sha256(contents.as_bytes());
+
+    for part in parts {
+        let variants: Vec<_> = registry::variants(year, day, part).collect();
+        if variants.is_empty() {
+            return Err(format!("no solver for {} day {} part {}", year, day, part).into());
+        }
+
+        println!(
+            "{} day {} part {} ({} bytes of input, sha256 {})",
+            year,
+            day,
+            part,
+            contents.len(),
+            sha
+        );
+        println!(
+            "{:<12} {:>12} {:>10} {:>10} {:>12}  answer",
+            "variant", "median", "speedup", "allocs", "alloc bytes"
+        );
+
+        let mut baseline: Option<(Duration, String)> = None;
+
+        for solver in variants {
+            let (answer, median, allocs) = if limits.isolate {
+                match isolate::solve(
+                    solver,
+                    contents.as_bytes(),
+                    Measure::Budget(config.budget),
+                    limits,
+                )? {
+                    Outcome::Solved { answer, time } => (answer, time, None),
+                    failed => {
+                        println!("{:<12} {}", solver.variant, failed);
+                        continue;
+                    }
+                }
+            } else {
+                // Count allocations on a separate run so the counting doesnt skew the samples
+                let before = counting_alloc::snapshot();
+                let answer = (solver.solve)(&contents);
+                let allocs = counting_alloc::snapshot().since(&before);
+
+                let median = bench::measure(&config, || (solver.solve)(&contents)).median();
+                (answer, median, Some(allocs))
+            };
+            let (base_median, base_answer) = baseline.get_or_insert((median, answer.clone()));
+
+            // Speedup over baseline, so > 1 means faster and < 1 means slower
+            let speedup = base_median.as_secs_f64() / median.as_secs_f64();
+
+            let (allocs, bytes) = match allocs {
+                Some(allocs) if counting_alloc::installed() => {
+                    (allocs.allocations.to_string(), allocs.bytes.to_string())
+                }
+                _ => ("-".to_string(), "-".to_string()),
+            };
+
+            let mismatch = if answer != *base_answer {
+                "  (DIFFERS FROM BASELINE)"
+            } else {
+                ""
+            };
+
+            println!(
+                "{:<12} {:>12} {:>9.2}x {:>10} {:>12}  {}{}",
+                solver.variant,
+                format!("{:?}", median),
+                speedup,
+                allocs,
+                bytes,
+                answer,
+                mismatch
+            );
+        }
+        println!();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use advent_of_code::cache::{self, AnswerCache};
+use advent_of_code::registry;
+
+use crate::args::Args;
+
+// Where run-all & verify look for inputs (data/<year>/day<#>.txt) and the answers file, unless
+// --data points somewhere else
+const DATA_DIR: &str = "data";
+const CACHE_PATH: &str = ".aoc-cache/answers";
+
+pub fn data_dir(args: &Args) -> Result<PathBuf, Box<dyn Error>> {
+    Ok(args
+        .value("data")?
+        .unwrap_or_else(|| PathBuf::from(DATA_DIR)))
+}
+
+pub fn input_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{}.txt", day))
+}
+
+// Every (year, day) run-all & verify go through, only one year's worth if --year is given
+pub fn puzzles(args: &Args) -> Result<Vec<(u16, u8)>, Box<dyn Error>> {
+    let only: Option<u16> = args.value("year")?;
+    Ok(registry::years()
+        .filter(|&year| only.is_none_or(|only| only == year))
+        .flat_map(|year| registry::days(year).map(move |day| (year, day)))
+        .collect())
+}
+
+pub fn answers_path(dir: &Path) -> PathBuf {
+    dir.join("answers.txt")
+}
+
+pub fn open_cache(args: &Args) -> Result<AnswerCache, Box<dyn Error>> {
+    if args.switch("no-cache") {
+        return Ok(AnswerCache::disabled());
+    }
+    Ok(AnswerCache::load(CACHE_PATH, cache::build_fingerprint()?)?)
+}
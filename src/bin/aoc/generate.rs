@@ -0,0 +1,28 @@
+use std::error::Error;
+use std::io::{self, Write};
+
+use advent_of_code::generators;
+
+use crate::args::Args;
+
+// aoc gen [year] <day> [--size <n>] [--seed <s>]
+// Writes a random but valid input to stdout, the same seed always gives the same input
+pub fn main(raw: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(raw, &["size", "seed"])?;
+    let (year, day, _) = args.puzzle()?;
+    let seed = args.value("seed")?.unwrap_or(2025);
+
+    // An empty input isnt a valid one for any day (day 5 would lose its blank line)
+    let size = match args.value("size")? {
+        Some(0) => return Err("--size has to be at least 1".into()),
+        Some(size) => size,
+        None => generators::default_size(year, day)
+            .ok_or_else(|| format!("no generator for {} day {}", year, day))?,
+    };
+
+    let input = generators::generate(year, day, size, seed)
+        .ok_or_else(|| format!("no generator for {} day {}", year, day))?;
+    io::stdout().lock().write_all(input.as_bytes())?;
+
+    Ok(())
+}
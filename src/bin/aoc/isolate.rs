@@ -0,0 +1,243 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{Read, Write};
+#[cfg(target_os = "linux")]
+use std::os::unix::process::{CommandExt, ExitStatusExt};
+use std::process::{Command, ExitStatus, Stdio};
+use std::time::{Duration, Instant};
+
+use advent_of_code::bench::{self, Config};
+use advent_of_code::registry::{self, Solver};
+
+use crate::args::Args;
+
+// Hidden subcommand the runner re-executes itself with, never meant to be typed by hand
+pub const CHILD_COMMAND: &str = "__part";
+
+// Only Linux for now: the limits are rlimits set between fork and exec, and the outcome is read
+// from the signal that ended the child
+const UNSUPPORTED: &str = "--isolate, --max-mem and --max-cpu are only supported on Linux";
+
+// rlimits put on the child process. Either one being set (or --isolate) runs parts out of process
+#[derive(Clone, Copy)]
+#[cfg_attr(not(target_os = "linux"), expect(dead_code))]
+pub struct Limits {
+    // RLIMIT_AS in bytes, allocations past this fail instead of the machine swapping itself to death
+    pub memory: Option<u64>,
+    // RLIMIT_CPU in seconds, the kernel sends SIGXCPU once it's used up
+    pub cpu: Option<u64>,
+    pub isolate: bool,
+}
+
+impl Limits {
+    // --max-mem is in MiB, --max-cpu in seconds
+    pub fn from_args(args: &Args) -> Result<Limits, Box<dyn Error>> {
+        let memory: Option<u64> = args.value("max-mem")?;
+        let cpu: Option<u64> = args.value("max-cpu")?;
+        let isolate = args.switch("isolate") || memory.is_some() || cpu.is_some();
+        if isolate && cfg!(not(target_os = "linux")) {
+            return Err(UNSUPPORTED.into());
+        }
+
+        Ok(Limits {
+            memory: memory.map(|mib| mib << 20),
+            cpu,
+            isolate,
+        })
+    }
+}
+
+// How the child should time the solver
+#[derive(Clone, Copy)]
+pub enum Measure {
+    // Average over n runs, like `aoc run --times`
+    Times(u32),
+    // Median over a time budget, like compare and scale
+    Budget(Duration),
+}
+
+#[cfg_attr(not(target_os = "linux"), expect(dead_code))]
+pub enum Outcome {
+    Solved { answer: String, time: Duration },
+    // Hit the address space limit, Rust aborts on the failed allocation
+    OutOfMemory,
+    // Got SIGKILL, which nothing here sends so it's almost always the kernel's OOM killer
+    Killed,
+    // Got SIGXCPU after using up --max-cpu
+    CpuExceeded,
+    Crashed(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Solved { answer, .. } => write!(f, "{}", answer),
+            Outcome::OutOfMemory => write!(f, "OUT OF MEMORY (hit --max-mem)"),
+            Outcome::Killed => write!(f, "KILLED (probably by the OOM killer)"),
+            Outcome::CpuExceeded => write!(f, "CPU LIMIT EXCEEDED (hit --max-cpu)"),
+            Outcome::Crashed(reason) => write!(f, "CRASHED ({})", reason),
+        }
+    }
+}
+
+// Runs one solver in a child process under the given limits. The input is handed over on stdin
+// so the child solves exactly what the parent read and normalized
+pub fn solve(
+    solver: &Solver,
+    input: &[u8],
+    measure: Measure,
+    limits: Limits,
+) -> Result<Outcome, Box<dyn Error>> {
+    let mut command = Command::new(std::env::current_exe()?);
+    command
+        .arg(CHILD_COMMAND)
+        .args([
+            solver.year.to_string(),
+            solver.day.to_string(),
+            solver.part.to_string(),
+            solver.variant.to_string(),
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    match measure {
+        Measure::Times(n) => command.args(["--times".to_string(), n.to_string()]),
+        Measure::Budget(budget) => {
+            command.args(["--budget".to_string(), budget.as_millis().to_string()])
+        }
+    };
+
+    set_limits(&mut command, limits)?;
+
+    let mut child = command.spawn()?;
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    // The child may die before reading everything, that shows up in its exit status instead
+    let _ = stdin.write_all(input);
+    drop(stdin);
+
+    let output = child.wait_with_output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    if output.status.success() {
+        let stdout = String::from_utf8(output.stdout)?;
+        let (nanos, answer) = stdout
+            .split_once('\n')
+            .ok_or("child process printed no result")?;
+        return Ok(Outcome::Solved {
+            answer: answer.trim_end().to_string(),
+            time: Duration::from_nanos(nanos.parse()?),
+        });
+    }
+
+    Ok(failure(output.status, &stderr))
+}
+
+#[cfg(target_os = "linux")]
+fn set_limits(command: &mut Command, limits: Limits) -> Result<(), Box<dyn Error>> {
+    // SAFETY: runs between fork and exec, setrlimit is async signal safe and nothing here
+    // allocates or takes locks
+    unsafe {
+        command.pre_exec(move || {
+            if let Some(bytes) = limits.memory {
+                set_limit(libc::RLIMIT_AS, bytes, bytes)?;
+            }
+            if let Some(secs) = limits.cpu {
+                // Hard limit a second later so SIGXCPU (soft) is what normally ends it
+                set_limit(libc::RLIMIT_CPU, secs, secs + 1)?;
+            }
+            Ok(())
+        });
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_limits(_command: &mut Command, _limits: Limits) -> Result<(), Box<dyn Error>> {
+    Err(UNSUPPORTED.into())
+}
+
+// glibc has its own type for the resource argument, everyone else uses int
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+type Resource = libc::__rlimit_resource_t;
+#[cfg(all(target_os = "linux", not(target_env = "gnu")))]
+type Resource = libc::c_int;
+
+#[cfg(target_os = "linux")]
+fn set_limit(resource: Resource, soft: u64, hard: u64) -> std::io::Result<()> {
+    let limit = libc::rlimit {
+        rlim_cur: soft,
+        rlim_max: hard,
+    };
+    // SAFETY: plain syscall on a valid pointer
+    if unsafe { libc::setrlimit(resource, &limit) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+// What went wrong with a child that didnt exit cleanly
+#[cfg(target_os = "linux")]
+fn failure(status: ExitStatus, stderr: &str) -> Outcome {
+    match status.signal() {
+        Some(libc::SIGXCPU) => Outcome::CpuExceeded,
+        Some(libc::SIGKILL) => Outcome::Killed,
+        Some(libc::SIGABRT) if stderr.contains("memory allocation of") => Outcome::OutOfMemory,
+        Some(signal) => Outcome::Crashed(format!("signal {}", signal)),
+        None => panicked(stderr),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn failure(_status: ExitStatus, stderr: &str) -> Outcome {
+    panicked(stderr)
+}
+
+fn panicked(stderr: &str) -> Outcome {
+    Outcome::Crashed(
+        stderr
+            .lines()
+            .find(|l| l.contains("panicked"))
+            .unwrap_or("non-zero exit")
+            .to_string(),
+    )
+}
+
+// aoc __part <year> <day> <part> <variant> [--times <n> | --budget <ms>]
+// Child side of `solve`: reads the input from stdin, prints the time in nanos and the answer
+pub fn child_main(raw: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(raw, &["times", "budget"])?;
+    let year = args.positional(0, "year")?;
+    let day = args.positional(1, "day")?;
+    let part = args.positional(2, "part")?;
+    let variant: String = args.positional(3, "variant")?;
+
+    let solver = registry::find(year, day, part, Some(&variant))
+        .ok_or_else(|| format!("no solver for {} day {} part {}", year, day, part))?;
+
+    let mut input = String::new();
+    std::io::stdin().read_to_string(&mut input)?;
+
+    let (time, answer) = match args.value("budget")? {
+        Some(ms) => {
+            let config = Config {
+                budget: Duration::from_millis(ms),
+                ..Config::default()
+            };
+            let answer = (solver.solve)(&input);
+            let median = bench::measure(&config, || (solver.solve)(&input)).median();
+            (median, answer)
+        }
+        None => {
+            let times = args.times()?;
+            let start = Instant::now();
+            let mut answer = String::new();
+            for _ in 0..times {
+                answer = (solver.solve)(&input);
+            }
+            (start.elapsed() / times, answer)
+        }
+    };
+
+    println!("{}\n{}", time.as_nanos(), answer);
+    Ok(())
+}
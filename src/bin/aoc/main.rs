@@ -0,0 +1,74 @@
+use std::error::Error;
+
+use advent_of_code::counting_alloc::CountingAlloc;
+
+mod args;
+mod batch;
+mod compare;
+mod data;
+mod generate;
+mod isolate;
+mod report;
+mod run;
+mod run_all;
+mod scale;
+mod serve;
+mod verify;
+mod vis;
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+const USAGE: &str = "\
+usage: aoc <command> [args]
+
+commands:
+  run [year] <day> <input> [--part <1|2>] [--variant <name>] [--times <n>]
+  compare [year] <day> <input> [--part <1|2>] [--budget <ms>]
+  run-all [--year <y>] [--data <dir>] [--no-cache]
+  verify [--year <y>] [--data <dir>] [--no-cache] [--record]
+  report [--year <y>] [--data <dir>] [--readme <file>] [--budget <ms>] [--no-cache]
+  batch [year] <day> <dir> [--variant <name>] [--answers <file>] [--no-cache]
+  gen [year] <day> [--size <n>] [--seed <s>]
+  scale [year] <day> [--part <1|2>] [--variant <name>] [--sizes <a,b,..>] [--seed <s>]
+  vis [year] <day> <input> [--delay <ms>] [--ppm <dir>] [--scale <px>]
+  serve [--bind <addr>] [--port <n>] [--max-connections <n>]
+
+the year defaults to the latest one with solutions, e.g. `aoc run 5 in.txt` is `aoc run 2025 5 in.txt`
+
+input options:
+  --raw            hand the file to solvers byte for byte
+  --keep-trailing  keep trailing blank lines (CRLF and BOM are still cleaned up)
+  --mmap           (run) memory map the input instead of reading it into a String
+  --stream         (run) feed the input to the solver line by line, <input> can be - for stdin
+
+run-all and verify read data/<year>/day<#>.txt and data/answers.txt (keyed by the input's sha256),
+and reuse answers cached in .aoc-cache/ when the input and the build havent changed.
+
+--isolate runs each part of run, compare and scale in a child process, --max-mem <MiB> and
+--max-cpu <s> put rlimits on it (and imply --isolate)
+
+-v / -vv show solver events (needs a build with --features trace)";
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    advent_of_code::trace::init_from_flags(&args);
+
+    match args.first().map(String::as_str) {
+        Some("run") => run::main(&args[1..]),
+        Some(isolate::CHILD_COMMAND) => isolate::child_main(&args[1..]),
+        Some("compare") => compare::main(&args[1..]),
+        Some("run-all") => run_all::main(&args[1..]),
+        Some("verify") => verify::main(&args[1..]),
+        Some("report") => report::main(&args[1..]),
+        Some("batch") => batch::main(&args[1..]),
+        Some("gen") => generate::main(&args[1..]),
+        Some("scale") => scale::main(&args[1..]),
+        Some("vis") => vis::main(&args[1..]),
+        Some("serve") => serve::main(&args[1..]),
+        _ => {
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    }
+}
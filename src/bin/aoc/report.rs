@@ -0,0 +1,130 @@
+use std::error::Error;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use advent_of_code::answers::Answers;
+use advent_of_code::bench::{self, Config};
+use advent_of_code::input::{self, Normalize};
+use advent_of_code::registry;
+
+use crate::args::Args;
+use crate::data;
+
+// The generated table goes between these two lines, everything else in the README is left alone
+const START_MARKER: &str = "<!-- progress:start -->";
+const END_MARKER: &str = "<!-- progress:end -->";
+
+// aoc report [--year <y>] [--data <dir>] [--readme <file>] [--budget <ms>] [--no-cache]
+// Verifies every variant against the answers file, benchmarks it on the real input and rewrites
+// the progress table in the README. Days without an input are still listed, just without results
+pub fn main(raw: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(raw, &["data", "year", "readme", "budget"])?;
+    let dir = data::data_dir(&args)?;
+    let answers = Answers::load(data::answers_path(&dir))?;
+    let mut cache = data::open_cache(&args)?;
+    let readme: PathBuf = args
+        .value("readme")?
+        .unwrap_or_else(|| PathBuf::from("README.md"));
+
+    let config = Config {
+        budget: Duration::from_millis(args.value("budget")?.unwrap_or(300)),
+        ..Config::default()
+    };
+
+    let mut table = String::from(
+        "| Year | Day | Part | Solved | Variants (median time) |\n\
+         |------|-----|------|--------|------------------------|\n",
+    );
+    let mut failures = 0;
+
+    for (year, day) in data::puzzles(&args)? {
+        let path = data::input_path(&dir, year, day);
+        let contents = if path.exists() {
+            Some(input::read(&path, Normalize::from_flags(raw))?)
+        } else {
+            None
+        };
+
+        for part in 1..=2 {
+            let variants: Vec<_> = registry::variants(year, day, part).collect();
+            if variants.is_empty() {
+                continue;
+            }
+
+            let Some(contents) = &contents else {
+                let names: Vec<&str> = variants.iter().map(|s| s.variant).collect();
+                writeln!(
+                    table,
+                    "| {} | {} | {} | no input | {} |",
+                    year,
+                    day,
+                    part,
+                    names.join(", ")
+                )?;
+                continue;
+            };
+
+            let sha = input::sha256(contents.as_bytes());
+            let expected = answers.get(&sha, year, day, part);
+
+            // Solved means every variant gives the recorded answer, one wrong variant is enough
+            // to not call the part solved
+            let mut wrong = 0;
+            let mut timings = Vec::new();
+            for solver in &variants {
+                let answer = cache.solve(solver, contents, &sha).answer;
+                if expected.is_some_and(|expected| expected != answer) {
+                    wrong += 1;
+                }
+
+                let median = bench::measure(&config, || (solver.solve)(contents)).median();
+                timings.push(format!("{} {:.2?}", solver.variant, median));
+            }
+            failures += wrong;
+
+            let solved = match expected {
+                None => "unverified".to_string(),
+                Some(_) if wrong == 0 => "yes".to_string(),
+                Some(_) => format!("WRONG ({} of {})", wrong, variants.len()),
+            };
+
+            writeln!(
+                table,
+                "| {} | {} | {} | {} | {} |",
+                year,
+                day,
+                part,
+                solved,
+                timings.join(", ")
+            )?;
+        }
+    }
+
+    cache.save()?;
+
+    let contents = fs::read_to_string(&readme)?;
+    let updated = replace_section(&contents, &table).ok_or_else(|| {
+        format!(
+            "{} needs a {} line followed by a {} line to put the table between",
+            readme.display(),
+            START_MARKER,
+            END_MARKER
+        )
+    })?;
+    fs::write(&readme, updated)?;
+    println!("{}", table.trim_end());
+
+    if failures > 0 {
+        return Err(format!("{} solver(s) disagree with the answers file", failures).into());
+    }
+    Ok(())
+}
+
+fn replace_section(readme: &str, table: &str) -> Option<String> {
+    let start = readme.find(START_MARKER)? + START_MARKER.len();
+    let end = start + readme[start..].find(END_MARKER)?;
+
+    Some(format!("{}\n{}{}", &readme[..start], table, &readme[end..]))
+}
@@ -0,0 +1,143 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::str::Utf8Error;
+use std::time::Instant;
+
+use advent_of_code::input::{self, Normalize};
+use advent_of_code::registry::{self, Solver};
+
+use crate::args::Args;
+use crate::isolate::{self, Limits, Measure, Outcome};
+
+// aoc run [year] <day> <input> [--part <1|2>] [--variant <name>] [--times <n>]
+//         [--isolate] [--max-mem <MiB>] [--max-cpu <s>] [--mmap] [--raw] [--keep-trailing]
+// --mmap is for huge inputs: the file is memory mapped instead of read into a String, and solvers
+// that take bytes get it without UTF-8 validation (the others validate it on every run)
+pub fn main(raw: &[String]) -> Result<(), Box<dyn Error>> {
+    if raw.iter().any(|a| a == "--stream") {
+        return stream(raw);
+    }
+
+    let args = Args::parse(raw, &["part", "variant", "times", "max-mem", "max-cpu"])?;
+    let (year, day, next) = args.puzzle()?;
+    let path: String = args.positional(next, "input")?;
+    let variant: Option<String> = args.value("variant")?;
+    let times = args.times()?;
+    let limits = Limits::from_args(&args)?;
+
+    let parts = match args.value("part")? {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let start = Instant::now();
+    let normalize = Normalize::from_flags(raw);
+    let contents = if args.switch("mmap") {
+        Contents::Mapped(input::read_bytes(path, normalize)?)
+    } else {
+        Contents::Text(input::read(path, normalize)?)
+    };
+    println!("IO duration:    {:?}", start.elapsed());
+    println!("Input SHA-256:  {}", input::sha256(contents.bytes()));
+
+    for part in parts {
+        let solver = registry::find(year, day, part, variant.as_deref())
+            .ok_or_else(|| format!("no solver for {} day {} part {}", year, day, part))?;
+
+        let outcome = if limits.isolate {
+            isolate::solve(solver, contents.bytes(), Measure::Times(times), limits)?
+        } else {
+            let start = Instant::now();
+            let mut answer = String::new();
+            for _ in 0..times {
+                answer = contents.solve(solver)?;
+            }
+            Outcome::Solved {
+                answer,
+                time: start.elapsed() / times,
+            }
+        };
+
+        println!("----------");
+        println!("Part: {}, Variant: {}", part, solver.variant);
+        println!("Result: {}", outcome);
+        if let Outcome::Solved { time, .. } = outcome {
+            println!("Compute (avg):  {:?}", time);
+        }
+    }
+
+    Ok(())
+}
+
+// aoc run [year] <day> <input|-> --stream [--part <1|2>] [--variant <name>] [--times <n>]
+//         [--raw] [--keep-trailing]
+// Feeds the input to the solver's line fold as it's read, so it runs in constant memory no matter
+// how big the input is. `-` reads stdin, e.g. piped from a generator. The input is hashed as it's
+// read, so the SHA-256 is the same as for a normal run on the same file with the same flags
+fn stream(raw: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(raw, &["part", "variant", "times"])?;
+    let (year, day, next) = args.puzzle()?;
+    let path: String = args.positional(next, "input")?;
+    let variant: Option<String> = args.value("variant")?;
+    let times = args.times()?;
+
+    let parts = match args.value("part")? {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let normalize = Normalize::from_flags(raw);
+    if path == "-" && (parts.len() > 1 || times > 1) {
+        return Err("stdin can only be read once, pick a --part and leave out --times".into());
+    }
+
+    for part in parts {
+        let solver = registry::find(year, day, part, variant.as_deref())
+            .ok_or_else(|| format!("no solver for {} day {} part {}", year, day, part))?;
+        let solve = solver
+            .solve_stream
+            .ok_or_else(|| format!("{} cant stream, it needs the whole input", solver.name()))?;
+
+        // Reading is part of the work when streaming, so its timed together with solving
+        let start = Instant::now();
+        let mut streamed = None;
+        for _ in 0..times {
+            let mut reader: Box<dyn BufRead> = if path == "-" {
+                Box::new(io::stdin().lock())
+            } else {
+                Box::new(BufReader::new(File::open(&path)?))
+            };
+            streamed = Some(solve(&mut reader, normalize)?);
+        }
+        let streamed = streamed.ok_or("--times has to be at least 1")?;
+
+        println!("----------");
+        println!("Part: {}, Variant: {} (streamed)", part, solver.variant);
+        println!("Input SHA-256:  {}", streamed.sha256);
+        println!("Result: {}", streamed.answer);
+        println!("Read + compute (avg):  {:?}", start.elapsed() / times);
+    }
+
+    Ok(())
+}
+
+enum Contents {
+    Text(String),
+    Mapped(input::Bytes),
+}
+
+impl Contents {
+    fn bytes(&self) -> &[u8] {
+        match self {
+            Contents::Text(text) => text.as_bytes(),
+            Contents::Mapped(bytes) => bytes,
+        }
+    }
+
+    fn solve(&self, solver: &Solver) -> Result<String, Utf8Error> {
+        match self {
+            Contents::Text(text) => Ok((solver.solve)(text)),
+            Contents::Mapped(bytes) => solver.solve_bytes(bytes),
+        }
+    }
+}
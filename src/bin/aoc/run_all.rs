@@ -0,0 +1,52 @@
+use std::error::Error;
+
+use advent_of_code::input::{self, Normalize};
+use advent_of_code::registry;
+
+use crate::args::Args;
+use crate::data;
+
+// aoc run-all [--year <y>] [--data <dir>] [--no-cache]
+// Runs the baseline of every part for each day that has an input in the data dir
+pub fn main(raw: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(raw, &["data", "year"])?;
+    let dir = data::data_dir(&args)?;
+    let mut cache = data::open_cache(&args)?;
+
+    println!(
+        "{:<5} {:<4} {:<5} {:<24} {:>14}  input sha256",
+        "year", "day", "part", "answer", "time"
+    );
+
+    for (year, day) in data::puzzles(&args)? {
+        let path = data::input_path(&dir, year, day);
+        if !path.exists() {
+            println!("{:<5} {:<4} no input at {}", year, day, path.display());
+            continue;
+        }
+
+        let contents = input::read(&path, Normalize::from_flags(raw))?;
+        let sha = input::sha256(contents.as_bytes());
+
+        for part in 1..=2 {
+            let Some(solver) = registry::find(year, day, part, None) else {
+                continue;
+            };
+
+            let outcome = cache.solve(solver, &contents, &sha);
+            println!(
+                "{:<5} {:<4} {:<5} {:<24} {:>14}  {}{}",
+                year,
+                day,
+                part,
+                outcome.answer,
+                format!("{:?}", outcome.time),
+                sha,
+                if outcome.cached { " (cached)" } else { "" }
+            );
+        }
+    }
+
+    cache.save()?;
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::time::Duration;
+
+use advent_of_code::bench::{self, Config};
+use advent_of_code::{generators, registry, scaling};
+
+use crate::args::Args;
+use crate::isolate::{self, Limits, Measure, Outcome};
+
+// aoc scale [year] <day> [--part <1|2>] [--variant <name>] [--sizes <a,b,..>] [--seed <s>]
+//           [--budget <ms>] [--isolate] [--max-mem <MiB>] [--max-cpu <s>]
+// Times a solver on generated inputs of growing size and fits the timings to n, n log n and n^2.
+// n is the input length in bytes so the fit doesnt depend on what --size means for each day.
+// With limits the sweep stops at the first size that runs out of memory or CPU time
+pub fn main(raw: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(
+        raw,
+        &[
+            "part", "variant", "sizes", "seed", "budget", "max-mem", "max-cpu",
+        ],
+    )?;
+    let (year, day, _) = args.puzzle()?;
+    let part = args.value("part")?.unwrap_or(1);
+    let variant: Option<String> = args.value("variant")?;
+    let seed = args.value("seed")?.unwrap_or(2025);
+
+    let solver = registry::find(year, day, part, variant.as_deref())
+        .ok_or_else(|| format!("no solver for {} day {} part {}", year, day, part))?;
+
+    // Default is 6 doublings ending at 4x the usual input size
+    let sizes: Vec<usize> = match args.value::<String>("sizes")? {
+        Some(list) => list
+            .split(',')
+            .map(|s| s.trim().parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| format!("invalid --sizes: {}", list))?,
+        None => {
+            let base = generators::default_size(year, day)
+                .ok_or_else(|| format!("no generator for {} day {}", year, day))?;
+            (0..6).map(|i| (base << i) / 8).filter(|&s| s > 1).collect()
+        }
+    };
+    if sizes.contains(&0) {
+        return Err("--sizes have to be at least 1".into());
+    }
+    if sizes.len() < 3 {
+        return Err("need at least 3 sizes to fit a curve".into());
+    }
+
+    let limits = Limits::from_args(&args)?;
+    let config = Config {
+        budget: Duration::from_millis(args.value("budget")?.unwrap_or(300)),
+        min_samples: 3,
+        ..Config::default()
+    };
+
+    println!("{} (seed {})", solver.name(), seed);
+    println!("{:>10} {:>12} {:>14}", "size", "bytes", "median");
+
+    let mut points = Vec::new();
+    for size in sizes {
+        let input = generators::generate(year, day, size, seed)
+            .ok_or_else(|| format!("no generator for {} day {}", year, day))?;
+        let median = if limits.isolate {
+            match isolate::solve(
+                solver,
+                input.as_bytes(),
+                Measure::Budget(config.budget),
+                limits,
+            )? {
+                Outcome::Solved { time, .. } => time,
+                failed => {
+                    println!("{:>10} {:>12} {}", size, input.len(), failed);
+                    break;
+                }
+            }
+        } else {
+            bench::measure(&config, || (solver.solve)(&input)).median()
+        };
+
+        println!(
+            "{:>10} {:>12} {:>14}",
+            size,
+            input.len(),
+            format!("{:?}", median)
+        );
+        points.push((input.len() as f64, median.as_secs_f64()));
+    }
+
+    if points.len() < 3 {
+        return Err("not enough sizes finished within the limits to fit a curve".into());
+    }
+
+    let fits = scaling::fit(&points);
+    println!("\n{:<10} {:>14}", "model", "rms log error");
+    for (i, fit) in fits.iter().enumerate() {
+        println!(
+            "{:<10} {:>14.3}{}",
+            fit.model.name(),
+            fit.error,
+            if i == 0 { "  <- best fit" } else { "" }
+        );
+    }
+    println!(
+        "log-log slope: {:.2} (t ~ n^k)\n",
+        scaling::exponent(&points)
+    );
+
+    print!("{}", scaling::ascii_plot(&points, &fits[0], 60, 16));
+    Ok(())
+}
@@ -0,0 +1,254 @@
+use std::error::Error;
+use std::fmt::Write as _;
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use advent_of_code::input::{self, Normalize};
+use advent_of_code::registry;
+
+use crate::args::Args;
+
+// Inputs are a few KiB, anything near this is a mistake or abuse
+const MAX_BODY: usize = 16 << 20;
+
+// A client that stops sending (or reading) gives up its thread after this
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+// aoc serve [--bind <addr>] [--port <n>] [--max-connections <n>] [--raw] [--keep-trailing]
+// Tiny HTTP/1.1 server so other tools can check answers without building this project:
+//   curl --data-binary @input.txt 'localhost:8025/day/5/part/1?variant=default&year=2025'
+// The body is the puzzle input, the reply is JSON with the answer and how long it took. One
+// request per connection, every connection on its own thread. Past --max-connections at once new
+// ones get a 503 right away
+pub fn main(raw: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(raw, &["bind", "port", "max-connections"])?;
+    let bind: String = args
+        .value("bind")?
+        .unwrap_or_else(|| "127.0.0.1".to_string());
+    let port: u16 = args.value("port")?.unwrap_or(8025);
+    let max_connections: usize = args.value("max-connections")?.unwrap_or(32);
+    let normalize = Normalize::from_flags(raw);
+
+    let listener = TcpListener::bind((bind.as_str(), port))?;
+    println!("listening on http://{}", listener.local_addr()?);
+
+    let open = Arc::new(AtomicUsize::new(0));
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("accept failed: {}", e);
+                continue;
+            }
+        };
+        if let Err(e) = stream
+            .set_read_timeout(Some(TIMEOUT))
+            .and_then(|_| stream.set_write_timeout(Some(TIMEOUT)))
+        {
+            eprintln!("connection error: {}", e);
+            continue;
+        }
+
+        if open.fetch_add(1, Ordering::SeqCst) >= max_connections {
+            open.fetch_sub(1, Ordering::SeqCst);
+            let busy = Response::error(503, "too many connections, try again later");
+            if let Err(e) = write_response(&stream, &busy) {
+                eprintln!("connection error: {}", e);
+            }
+            continue;
+        }
+
+        let slot = Slot(open.clone());
+        thread::spawn(move || {
+            let _slot = slot;
+            match handle(stream, normalize) {
+                Ok(()) => {}
+                Err(e) if timed_out(&*e) => eprintln!("connection timed out"),
+                Err(e) => eprintln!("connection error: {}", e),
+            }
+        });
+    }
+    Ok(())
+}
+
+// Read timeouts show up as WouldBlock on unix
+fn timed_out(e: &(dyn Error + 'static)) -> bool {
+    e.downcast_ref::<io::Error>()
+        .is_some_and(|e| matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut))
+}
+
+// Gives the connection's place back when its thread ends, also if it panics
+struct Slot(Arc<AtomicUsize>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn error(status: u16, message: &str) -> Response {
+        Response {
+            status,
+            body: format!("{{\"error\":{}}}", json_string(message)),
+        }
+    }
+}
+
+fn handle(stream: TcpStream, normalize: Normalize) -> Result<(), Box<dyn Error>> {
+    let mut reader = BufReader::new(&stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut fields = request_line.split_whitespace();
+    let method = fields.next().unwrap_or_default().to_string();
+    let target = fields.next().unwrap_or_default().to_string();
+
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse::<usize>().ok();
+        }
+    }
+
+    let response = match (method.as_str(), content_length) {
+        ("POST", Some(len)) if len <= MAX_BODY => {
+            let mut body = vec![0; len];
+            reader.read_exact(&mut body)?;
+            match String::from_utf8(body) {
+                Ok(body) => solve(&target, input::normalize(body, normalize)),
+                Err(_) => Response::error(400, "input is not valid UTF-8"),
+            }
+        }
+        ("POST", Some(_)) => Response::error(413, "input too large"),
+        ("POST", None) => Response::error(411, "Content-Length required"),
+        _ => Response::error(405, "POST the input to /day/{n}/part/{p}"),
+    };
+
+    println!("{} {} -> {}", method, target, response.status);
+    write_response(&stream, &response)
+}
+
+fn solve(target: &str, input: String) -> Response {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let (day, part) = match segments[..] {
+        ["day", day, "part", part] => match (day.parse::<u8>(), part.parse::<u8>()) {
+            (Ok(day), Ok(part)) => (day, part),
+            _ => return Response::error(400, "day and part must be numbers"),
+        },
+        _ => return Response::error(404, "expected /day/{n}/part/{p}"),
+    };
+
+    let mut year = registry::latest_year();
+    let mut variant = None;
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        match pair.split_once('=') {
+            Some(("variant", name)) => variant = Some(name),
+            Some(("year", y)) => match y.parse() {
+                Ok(y) => year = y,
+                Err(_) => return Response::error(400, "year must be a number"),
+            },
+            _ => return Response::error(400, &format!("unknown query parameter: {}", pair)),
+        }
+    }
+
+    let Some(solver) = registry::find(year, day, part, variant) else {
+        return Response::error(
+            404,
+            &format!("no solver for {} day {} part {}", year, day, part),
+        );
+    };
+
+    let start = Instant::now();
+    // The default hook already logs the panic to stderr, this only turns it into a response.
+    // Connections are handled on several threads, so the hook cant be swapped out like batch does
+    let answer = match panic::catch_unwind(AssertUnwindSafe(|| (solver.solve)(&input))) {
+        Ok(answer) => answer,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            return Response::error(422, &format!("solver failed: {}", message));
+        }
+    };
+    let time = start.elapsed();
+
+    let mut body = String::new();
+    write!(
+        body,
+        "{{\"year\":{},\"day\":{},\"part\":{},\"variant\":{},\"answer\":{},\"time_ns\":{},\"input_sha256\":\"{}\"}}",
+        year,
+        day,
+        part,
+        json_string(solver.variant),
+        json_string(&answer),
+        time.as_nanos(),
+        input::sha256(input.as_bytes())
+    )
+    .unwrap();
+
+    Response { status: 200, body }
+}
+
+fn write_response(mut stream: &TcpStream, response: &Response) -> Result<(), Box<dyn Error>> {
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Content",
+        503 => "Service Unavailable",
+        _ => "Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
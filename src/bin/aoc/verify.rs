@@ -0,0 +1,86 @@
+use std::error::Error;
+
+use advent_of_code::answers::Answers;
+use advent_of_code::input::{self, Normalize};
+use advent_of_code::registry;
+
+use crate::args::Args;
+use crate::data;
+
+// aoc verify [--year <y>] [--data <dir>] [--no-cache] [--record]
+// Checks every variant against the answers file. --record fills in parts that have no answer yet
+// using the baseline variant, so a new day only has to be confirmed on the website once
+pub fn main(raw: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(raw, &["data", "year"])?;
+    let dir = data::data_dir(&args)?;
+    let answers_path = data::answers_path(&dir);
+    let mut answers = Answers::load(&answers_path)?;
+    let mut cache = data::open_cache(&args)?;
+    let record = args.switch("record");
+
+    let mut failures = 0;
+    let mut recorded = 0;
+
+    println!(
+        "{:<5} {:<4} {:<5} {:<12} {:<24} {:>14}  {:<64}  status",
+        "year", "day", "part", "variant", "answer", "time", "input sha256"
+    );
+
+    for (year, day) in data::puzzles(&args)? {
+        let path = data::input_path(&dir, year, day);
+        if !path.exists() {
+            continue;
+        }
+
+        let contents = input::read(&path, Normalize::from_flags(raw))?;
+        let sha = input::sha256(contents.as_bytes());
+
+        for part in 1..=2 {
+            for solver in registry::variants(year, day, part) {
+                let outcome = cache.solve(solver, &contents, &sha);
+
+                let status = match answers.get(&sha, year, day, part) {
+                    Some(expected) if expected == outcome.answer => "ok".to_string(),
+                    Some(expected) => {
+                        failures += 1;
+                        format!("FAIL (expected {})", expected)
+                    }
+                    None if record => {
+                        answers.insert(&sha, year, day, part, outcome.answer.clone());
+                        recorded += 1;
+                        "recorded".to_string()
+                    }
+                    None => "no answer".to_string(),
+                };
+
+                println!(
+                    "{:<5} {:<4} {:<5} {:<12} {:<24} {:>14}  {}  {}{}",
+                    year,
+                    day,
+                    part,
+                    solver.variant,
+                    outcome.answer,
+                    format!("{:?}", outcome.time),
+                    sha,
+                    status,
+                    if outcome.cached { " (cached)" } else { "" }
+                );
+            }
+        }
+    }
+
+    cache.save()?;
+    if recorded > 0 {
+        answers.save(&answers_path)?;
+    }
+
+    if failures > 0 {
+        return Err(format!(
+            "{} solver(s) disagree with {}",
+            failures,
+            answers_path.display()
+        )
+        .into());
+    }
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use std::error::Error;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use advent_of_code::input::{self, Normalize};
+use advent_of_code::registry::VISUALIZERS;
+use advent_of_code::vis::{self, Recorder};
+
+use crate::args::Args;
+
+// aoc vis [year] <day> <input> [--delay <ms>] [--ppm <dir>] [--scale <px>]
+// Plays the frames in the terminal, or writes them out as a PPM sequence when --ppm is given
+pub fn main(raw: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(raw, &["delay", "ppm", "scale"])?;
+    let (year, day, next) = args.puzzle()?;
+    let path: String = args.positional(next, "input")?;
+
+    let visualizer = VISUALIZERS
+        .iter()
+        .find(|v| v.year == year && v.day == day)
+        .ok_or_else(|| format!("no visualizer for {} day {}", year, day))?;
+
+    let contents = input::read(path, Normalize::from_flags(raw))?;
+    let mut recorder = Recorder::default();
+    (visualizer.visualize)(&contents, &mut recorder);
+
+    match args.value::<PathBuf>("ppm")? {
+        Some(dir) => {
+            let scale = args.value("scale")?.unwrap_or(4);
+            vis::write_ppm_sequence(&recorder.frames, &dir, scale)?;
+            println!(
+                "Wrote {} frames to {}",
+                recorder.frames.len(),
+                dir.display()
+            );
+        }
+        None => {
+            let delay = Duration::from_millis(args.value("delay")?.unwrap_or(100));
+            vis::render_ansi(&recorder.frames, delay, &mut io::stdout().lock())?;
+        }
+    }
+
+    Ok(())
+}
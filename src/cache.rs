@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::input;
+use crate::registry::Solver;
+
+// Identifies the solver code that produced an answer. Any rebuild that changes the code changes
+// the binary, so hashing the running executable invalidates the cache whenever a solver changes
+pub fn build_fingerprint() -> io::Result<String> {
+    let exe = std::env::current_exe()?;
+    Ok(input::sha256(&fs::read(exe)?))
+}
+
+#[derive(Hash, PartialEq, Eq)]
+struct Key {
+    input: String,
+    year: u16,
+    day: u8,
+    part: u8,
+    variant: String,
+}
+
+struct Entry {
+    time: Duration,
+    answer: String,
+}
+
+pub struct Outcome {
+    pub answer: String,
+    // For a cached answer this is how long the original run took
+    pub time: Duration,
+    pub cached: bool,
+}
+
+// Answers already computed for a given input, solver and build. Stored as one line per entry:
+// `<input sha256> <year> <day> <part> <variant> <build sha256> <nanos> <answer>`
+pub struct AnswerCache {
+    path: PathBuf,
+    build: String,
+    entries: HashMap<Key, Entry>,
+    enabled: bool,
+    dirty: bool,
+}
+
+impl AnswerCache {
+    pub fn load<P: AsRef<Path>>(path: P, build: String) -> io::Result<AnswerCache> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        // Unreadable lines are just dropped, worst case we recompute
+        let entries = contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(8, ' ');
+                let input = fields.next()?.to_string();
+                let year = fields.next()?.parse().ok()?;
+                let day = fields.next()?.parse().ok()?;
+                let part = fields.next()?.parse().ok()?;
+                let variant = fields.next()?.to_string();
+                let entry_build = fields.next()?;
+                let nanos = fields.next()?.parse().ok()?;
+                let answer = fields.next()?.to_string();
+
+                // Anything from an older build is stale
+                (entry_build == build).then_some((
+                    Key {
+                        input,
+                        year,
+                        day,
+                        part,
+                        variant,
+                    },
+                    Entry {
+                        time: Duration::from_nanos(nanos),
+                        answer,
+                    },
+                ))
+            })
+            .collect();
+
+        Ok(AnswerCache {
+            path,
+            build,
+            entries,
+            enabled: true,
+            dirty: false,
+        })
+    }
+
+    // Always recomputes and never writes anything (--no-cache)
+    pub fn disabled() -> AnswerCache {
+        AnswerCache {
+            path: PathBuf::new(),
+            build: String::new(),
+            entries: HashMap::new(),
+            enabled: false,
+            dirty: false,
+        }
+    }
+
+    // Returns the cached answer if there is one, otherwise runs the solver and remembers the result
+    pub fn solve(&mut self, solver: &Solver, input: &str, input_sha: &str) -> Outcome {
+        let key = Key {
+            input: input_sha.to_string(),
+            year: solver.year,
+            day: solver.day,
+            part: solver.part,
+            variant: solver.variant.to_string(),
+        };
+
+        if let Some(entry) = self.entries.get(&key) {
+            return Outcome {
+                answer: entry.answer.clone(),
+                time: entry.time,
+                cached: true,
+            };
+        }
+
+        let start = Instant::now();
+        let answer = (solver.solve)(input);
+        let time = start.elapsed();
+
+        if self.enabled {
+            self.entries.insert(
+                key,
+                Entry {
+                    time,
+                    answer: answer.clone(),
+                },
+            );
+            self.dirty = true;
+        }
+
+        Outcome {
+            answer,
+            time,
+            cached: false,
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut out = String::new();
+        for (key, entry) in &self.entries {
+            out.push_str(&format!(
+                "{} {} {} {} {} {} {} {}\n",
+                key.input,
+                key.year,
+                key.day,
+                key.part,
+                key.variant,
+                self.build,
+                entry.time.as_nanos(),
+                entry.answer
+            ));
+        }
+        fs::write(&self.path, out)
+    }
+}
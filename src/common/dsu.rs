@@ -0,0 +1,53 @@
+// Disjoint set union, for puzzles that keep merging groups (day8 2025 circuits) and need to
+// quickly check whether two items are already in the same group
+pub struct Dsu {
+    parent: Vec<usize>,
+    pub size: Vec<usize>,
+    pub num_components: usize,
+}
+
+impl Dsu {
+    pub fn new(n: usize) -> Self {
+        Dsu {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            num_components: n,
+        }
+    }
+
+    pub fn find(&mut self, i: usize) -> usize {
+        if self.parent[i] != i {
+            self.parent[i] = self.find(self.parent[i]);
+        }
+        self.parent[i]
+    }
+
+    pub fn union(&mut self, i: usize, j: usize) -> bool {
+        let root_i = self.find(i);
+        let root_j = self.find(j);
+
+        if root_i != root_j {
+            let (child, root) = if self.size[root_j] < self.size[root_i] {
+                (root_i, root_j)
+            } else {
+                (root_j, root_i)
+            };
+            self.parent[child] = root;
+            self.size[root] += self.size[child];
+
+            self.num_components -= 1;
+            crate::trace!(
+                2,
+                "dsu.union",
+                a = i,
+                b = j,
+                root = root,
+                size = self.size[root],
+                components = self.num_components
+            );
+            true
+        } else {
+            false
+        }
+    }
+}
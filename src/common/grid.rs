@@ -0,0 +1,76 @@
+use std::ops::{Index, IndexMut, Range};
+
+// Character grid flattened into one Vec with a 1 cell border of padding all around, so neighbor
+// lookups never need bounds checks (thus avoiding branches). Cell (x, y) of the original diagram
+// lives at (y + 1) * stride + (x + 1)
+pub struct PaddedGrid {
+    pub cells: Vec<u8>,
+    pub width: usize,
+    pub height: usize,
+    pub stride: usize,
+}
+
+impl PaddedGrid {
+    // Width is taken from the first line, every line is assumed to be that long
+    pub fn parse(diagram: &str, pad: u8) -> Self {
+        let width = diagram.lines().next().map_or(0, str::len);
+        let height = diagram.lines().count();
+
+        let stride = width + 2;
+        let mut cells = vec![pad; stride * (height + 2)];
+
+        // Copy values from diagram
+        for (row_idx, line) in diagram.lines().enumerate() {
+            // Skip borders with +1
+            let start = (row_idx + 1) * stride + 1;
+            cells[start..start + width].copy_from_slice(line.as_bytes());
+        }
+
+        PaddedGrid {
+            cells,
+            width,
+            height,
+            stride,
+        }
+    }
+
+    // Index deltas of the 8 surrounding cells, in reading order
+    pub fn neighbor_offsets(&self) -> [isize; 8] {
+        let s = self.stride as isize;
+        [-s - 1, -s, -s + 1, -1, 1, s - 1, s, s + 1]
+    }
+
+    // Padded index range of each row of the original diagram
+    pub fn rows(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        (1..=self.height).map(|y| {
+            let row_start = y * self.stride + 1;
+            row_start..row_start + self.width
+        })
+    }
+
+    // Maps a padded index back to an index into the unpadded width x height grid
+    pub fn unpadded_index(&self, i: usize) -> usize {
+        (i / self.stride - 1) * self.width + (i % self.stride - 1)
+    }
+
+    // The original diagram without padding or newlines
+    pub fn unpadded(&self) -> Vec<u8> {
+        self.rows()
+            .flat_map(|row| self.cells[row].iter().copied())
+            .collect()
+    }
+}
+
+impl Index<usize> for PaddedGrid {
+    type Output = u8;
+
+    fn index(&self, i: usize) -> &u8 {
+        &self.cells[i]
+    }
+}
+
+impl IndexMut<usize> for PaddedGrid {
+    fn index_mut(&mut self, i: usize) -> &mut u8 {
+        &mut self.cells[i]
+    }
+}
@@ -0,0 +1,5 @@
+// Building blocks shared by every year's solutions
+pub mod dsu;
+pub mod grid;
+pub mod parse;
+pub mod scan;
@@ -0,0 +1,388 @@
+// Allocation free number parsing straight off the input bytes. str::parse after a split has to
+// find every separator first and then validates UTF-8 and signs again for each piece, these do a
+// single pass. Errors carry the byte offset into the slice handed in, so a bad input points at
+// the exact spot instead of just "invalid digit"
+use std::error::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ErrorKind {
+    ExpectedDigit,
+    Overflow,
+    Expected(u8),
+    TrailingBytes,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    pub pos: usize,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ErrorKind::ExpectedDigit => write!(f, "expected a digit at byte {}", self.pos),
+            ErrorKind::Overflow => write!(f, "number at byte {} doesnt fit", self.pos),
+            ErrorKind::Expected(b) => write!(f, "expected {:?} at byte {}", b as char, self.pos),
+            ErrorKind::TrailingBytes => write!(f, "unexpected bytes from byte {}", self.pos),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// Integer types the parsers can produce. Digits are added with checked math so overflow is an
+// error and not a wrong answer
+pub trait Int: Clone + 'static {
+    const ZERO: Self;
+    // self * 10 + digit
+    fn push_digit(self, digit: u8) -> Option<Self>;
+    // self * 10 - digit, negatives are accumulated downwards so MIN parses too
+    fn push_digit_neg(self, digit: u8) -> Option<Self>;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(impl Int for $t {
+            const ZERO: Self = 0;
+
+            #[inline(always)]
+            fn push_digit(self, digit: u8) -> Option<Self> {
+                self.checked_mul(10)?.checked_add(digit as Self)
+            }
+
+            #[inline(always)]
+            fn push_digit_neg(self, digit: u8) -> Option<Self> {
+                self.checked_mul(10)?.checked_sub(digit as Self)
+            }
+        })*
+    };
+}
+
+impl_int!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+#[cfg(feature = "bignum")]
+impl Int for num_bigint::BigUint {
+    const ZERO: Self = num_bigint::BigUint::ZERO;
+
+    fn push_digit(self, digit: u8) -> Option<Self> {
+        Some(self * 10u8 + digit)
+    }
+
+    // Unsigned, so theres nothing below zero to accumulate into
+    fn push_digit_neg(self, digit: u8) -> Option<Self> {
+        (digit == 0 && self == Self::ZERO).then_some(self)
+    }
+}
+
+// Reads values front to back, keeping track of the position for errors
+pub struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Cursor { bytes, pos: 0 }
+    }
+
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    // Consumes `b` if it's next
+    pub fn eat(&mut self, b: u8) -> bool {
+        let found = self.peek() == Some(b);
+        self.pos += found as usize;
+        found
+    }
+
+    pub fn expect(&mut self, b: u8) -> Result<(), ParseError> {
+        if self.eat(b) {
+            Ok(())
+        } else {
+            Err(self.error(ErrorKind::Expected(b)))
+        }
+    }
+
+    // Spaces, tabs and newlines
+    pub fn skip_whitespace(&mut self) {
+        self.skip_while(|b| b.is_ascii_whitespace());
+    }
+
+    // Spaces and tabs only, for inputs where newlines mean something. A \r counts as a space too,
+    // so CRLF files (e.g. with --raw) still end lines at the \n
+    pub fn skip_spaces(&mut self) {
+        self.skip_while(|b| b == b' ' || b == b'\t' || b == b'\r');
+    }
+
+    #[inline(always)]
+    fn skip_while(&mut self, pred: impl Fn(u8) -> bool) {
+        self.pos += self.bytes[self.pos.min(self.bytes.len())..]
+            .iter()
+            .take_while(|&&b| pred(b))
+            .count();
+    }
+
+    pub fn unsigned<T: Int>(&mut self) -> Result<T, ParseError> {
+        self.digits::<T, false>()
+    }
+
+    // An optional + or - followed by digits
+    pub fn signed<T: Int>(&mut self) -> Result<T, ParseError> {
+        if self.eat(b'-') {
+            self.digits::<T, true>()
+        } else {
+            self.eat(b'+');
+            self.unsigned()
+        }
+    }
+
+    // a-b, both unsigned
+    pub fn range<T: Int>(&mut self) -> Result<RangeInclusive<T>, ParseError> {
+        let start = self.unsigned()?;
+        self.expect(b'-')?;
+        Ok(start..=self.unsigned()?)
+    }
+
+    #[inline(always)]
+    fn digits<T: Int, const NEG: bool>(&mut self) -> Result<T, ParseError> {
+        let start = self.pos;
+        let mut n = T::ZERO;
+
+        while let Some(&b) = self.bytes.get(self.pos) {
+            let digit = b.wrapping_sub(b'0');
+            if digit > 9 {
+                break;
+            }
+            let next = if NEG {
+                n.push_digit_neg(digit)
+            } else {
+                n.push_digit(digit)
+            };
+            n = next.ok_or(ParseError {
+                pos: start,
+                kind: ErrorKind::Overflow,
+            })?;
+            self.pos += 1;
+        }
+
+        if self.pos == start {
+            return Err(self.error(ErrorKind::ExpectedDigit));
+        }
+        Ok(n)
+    }
+
+    fn error(&self, kind: ErrorKind) -> ParseError {
+        ParseError {
+            pos: self.pos,
+            kind,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+    // Any run of spaces, tabs and newlines
+    Whitespace,
+    // A comma, optionally with whitespace around it
+    Comma,
+}
+
+// Lazily parses `item`s separated by `sep`. Whitespace at the start and end is ignored, so a
+// trailing newline doesnt need trimming first. Stops at the first error
+pub fn separated<'a, T: 'a>(
+    bytes: &'a [u8],
+    sep: Separator,
+    item: fn(&mut Cursor<'a>) -> Result<T, ParseError>,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a {
+    let mut cursor = Cursor::new(bytes);
+    cursor.skip_whitespace();
+    let mut failed = false;
+
+    std::iter::from_fn(move || {
+        if failed || cursor.is_empty() {
+            return None;
+        }
+
+        let result = item(&mut cursor).and_then(|value| {
+            cursor.skip_whitespace();
+            if sep == Separator::Comma && !cursor.is_empty() {
+                cursor.expect(b',')?;
+                cursor.skip_whitespace();
+            } else if sep == Separator::Whitespace
+                && !cursor.is_empty()
+                && !bytes[cursor.pos() - 1].is_ascii_whitespace()
+            {
+                // Something other than whitespace right after the value
+                return Err(cursor.error(ErrorKind::TrailingBytes));
+            }
+            Ok(value)
+        });
+
+        failed = result.is_err();
+        Some(result)
+    })
+}
+
+pub fn list<T: Int>(
+    bytes: &[u8],
+    sep: Separator,
+) -> impl Iterator<Item = Result<T, ParseError>> + '_ {
+    separated(bytes, sep, Cursor::unsigned)
+}
+
+pub fn signed_list<T: Int>(
+    bytes: &[u8],
+    sep: Separator,
+) -> impl Iterator<Item = Result<T, ParseError>> + '_ {
+    separated(bytes, sep, Cursor::signed)
+}
+
+pub fn ranges<T: Int>(
+    bytes: &[u8],
+    sep: Separator,
+) -> impl Iterator<Item = Result<RangeInclusive<T>, ParseError>> + '_ {
+    separated(bytes, sep, Cursor::range)
+}
+
+// str::lines for bytes: split on \n, a \r before it is dropped and theres no empty last line
+pub fn lines(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
+    bytes.split_inclusive(|&b| b == b'\n').map(|line| {
+        let line = line.strip_suffix(b"\n").unwrap_or(line);
+        line.strip_suffix(b"\r").unwrap_or(line)
+    })
+}
+
+// The whole slice as one number, surrounding whitespace allowed
+pub fn unsigned<T: Int>(bytes: &[u8]) -> Result<T, ParseError> {
+    whole(bytes, Cursor::unsigned)
+}
+
+pub fn signed<T: Int>(bytes: &[u8]) -> Result<T, ParseError> {
+    whole(bytes, Cursor::signed)
+}
+
+pub fn range<T: Int>(bytes: &[u8]) -> Result<RangeInclusive<T>, ParseError> {
+    whole(bytes, Cursor::range)
+}
+
+fn whole<'a, T>(
+    bytes: &'a [u8],
+    item: fn(&mut Cursor<'a>) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let mut cursor = Cursor::new(bytes);
+    cursor.skip_whitespace();
+    let value = item(&mut cursor)?;
+    cursor.skip_whitespace();
+
+    if !cursor.is_empty() {
+        return Err(cursor.error(ErrorKind::TrailingBytes));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(pos: usize, kind: ErrorKind) -> ParseError {
+        ParseError { pos, kind }
+    }
+
+    #[test]
+    fn whole_numbers() {
+        assert_eq!(unsigned::<u64>(b" 42\n"), Ok(42));
+        assert_eq!(signed::<i8>(b"-128"), Ok(-128));
+        assert_eq!(signed::<i32>(b"+7"), Ok(7));
+        assert_eq!(range::<u32>(b"3-15"), Ok(3..=15));
+    }
+
+    #[test]
+    fn error_positions() {
+        assert_eq!(
+            unsigned::<u64>(b""),
+            Err(error(0, ErrorKind::ExpectedDigit))
+        );
+        assert_eq!(
+            unsigned::<u64>(b"  x"),
+            Err(error(2, ErrorKind::ExpectedDigit))
+        );
+        assert_eq!(
+            unsigned::<u64>(b"12x"),
+            Err(error(2, ErrorKind::TrailingBytes))
+        );
+        assert_eq!(
+            unsigned::<u64>(b"-1"),
+            Err(error(0, ErrorKind::ExpectedDigit))
+        );
+        assert_eq!(
+            signed::<i64>(b"- 1"),
+            Err(error(1, ErrorKind::ExpectedDigit))
+        );
+        // Overflow points at the start of the number, not the digit that didnt fit
+        assert_eq!(unsigned::<u8>(b" 256"), Err(error(1, ErrorKind::Overflow)));
+        assert_eq!(signed::<i8>(b"-129"), Err(error(1, ErrorKind::Overflow)));
+        assert_eq!(
+            range::<u32>(b"3+15"),
+            Err(error(1, ErrorKind::Expected(b'-')))
+        );
+        assert_eq!(range::<u32>(b"3-"), Err(error(2, ErrorKind::ExpectedDigit)));
+    }
+
+    #[test]
+    fn separated_lists() {
+        let parsed: Result<Vec<u32>, _> = list(b" 1 2\n3\t4 \n", Separator::Whitespace).collect();
+        assert_eq!(parsed, Ok(vec![1, 2, 3, 4]));
+
+        let parsed: Result<Vec<u32>, _> = list(b"1, 2 ,3\n", Separator::Comma).collect();
+        assert_eq!(parsed, Ok(vec![1, 2, 3]));
+
+        let parsed: Vec<_> = ranges::<u32>(b"1-2,3-4", Separator::Comma).collect();
+        assert_eq!(parsed, vec![Ok(1..=2), Ok(3..=4)]);
+    }
+
+    #[test]
+    fn separated_errors() {
+        let parsed: Vec<_> = list::<u32>(b"1 2x 3", Separator::Whitespace).collect();
+        assert_eq!(parsed, vec![Ok(1), Err(error(3, ErrorKind::TrailingBytes))]);
+
+        let parsed: Vec<_> = list::<u32>(b"1,2;3", Separator::Comma).collect();
+        assert_eq!(
+            parsed,
+            vec![Ok(1), Err(error(3, ErrorKind::Expected(b',')))]
+        );
+
+        let parsed: Vec<_> = list::<u8>(b"1 300 2", Separator::Whitespace).collect();
+        assert_eq!(parsed, vec![Ok(1), Err(error(2, ErrorKind::Overflow))]);
+    }
+
+    #[test]
+    fn cursor_spaces() {
+        let mut cursor = Cursor::new(b"1 \t\r\n2");
+        assert_eq!(cursor.unsigned::<u32>(), Ok(1));
+        cursor.skip_spaces();
+        assert!(cursor.eat(b'\n'));
+        assert_eq!(cursor.unsigned::<u32>(), Ok(2));
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn byte_lines() {
+        let split: Vec<&[u8]> = lines(b"a\r\nb\n\nc").collect();
+        assert_eq!(split, [&b"a"[..], b"b", b"", b"c"]);
+        assert_eq!(lines(b"a\n").count(), 1);
+    }
+}
@@ -0,0 +1,156 @@
+// Parallel folds for puzzles that are one long sequential fold. If the fold step can be summarized
+// as something associative (a monoid), each thread summarizes its own chunk and the summaries are
+// combined in order afterwards, so the order of the input still matters but the work doesnt have
+// to wait on it
+use std::thread;
+
+pub trait Monoid: Send {
+    // Summary of nothing, combining with it changes nothing
+    fn empty() -> Self;
+    // `self` followed by `next`, has to be associative but not commutative
+    fn combine(&self, next: &Self) -> Self;
+}
+
+// One chunk per core, at least one item each
+fn chunk_size(len: usize) -> usize {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    len.div_ceil(threads).max(1)
+}
+
+// Summarizes every chunk on its own thread, then combines the summaries front to back
+pub fn reduce<T, M, F>(items: &[T], summarize: F) -> M
+where
+    T: Sync,
+    M: Monoid,
+    F: Fn(&[T]) -> M + Sync,
+{
+    reduce_chunks(items, chunk_size(items.len()), summarize)
+}
+
+// Same with a given chunk size instead of one chunk per core. Associativity means the result
+// cant depend on it, which is what the tests check
+pub fn reduce_chunks<T, M, F>(items: &[T], chunk_size: usize, summarize: F) -> M
+where
+    T: Sync,
+    M: Monoid,
+    F: Fn(&[T]) -> M + Sync,
+{
+    summaries(items, chunk_size, &summarize)
+        .iter()
+        .fold(M::empty(), |acc, summary| acc.combine(summary))
+}
+
+// Two pass scan: chunks are summarized in parallel, an exclusive prefix of the summaries is built
+// (cheap, one per chunk), then `finish` gets every chunk together with the summary of everything
+// before it, again in parallel. Returns what `finish` returned for each chunk, in order
+pub fn scan<T, M, R, F, G>(items: &[T], summarize: F, finish: G) -> Vec<R>
+where
+    T: Sync,
+    M: Monoid + Sync,
+    R: Send,
+    F: Fn(&[T]) -> M + Sync,
+    G: Fn(&M, &[T]) -> R + Sync,
+{
+    scan_chunks(items, chunk_size(items.len()), summarize, finish)
+}
+
+pub fn scan_chunks<T, M, R, F, G>(items: &[T], chunk_size: usize, summarize: F, finish: G) -> Vec<R>
+where
+    T: Sync,
+    M: Monoid + Sync,
+    R: Send,
+    F: Fn(&[T]) -> M + Sync,
+    G: Fn(&M, &[T]) -> R + Sync,
+{
+    let summaries = summaries(items, chunk_size, &summarize);
+
+    let mut prefixes = Vec::with_capacity(summaries.len());
+    let mut acc = M::empty();
+    for summary in &summaries {
+        let next = acc.combine(summary);
+        prefixes.push(acc);
+        acc = next;
+    }
+
+    let finish = &finish;
+    thread::scope(|s| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .zip(&prefixes)
+            .map(|(chunk, prefix)| s.spawn(move || finish(prefix, chunk)))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+fn summaries<T, M, F>(items: &[T], chunk_size: usize, summarize: &F) -> Vec<M>
+where
+    T: Sync,
+    M: Monoid,
+    F: Fn(&[T]) -> M + Sync,
+{
+    assert!(chunk_size > 0, "Chunks need at least one item each");
+    thread::scope(|s| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(move || summarize(chunk)))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Plain addition, the simplest monoid
+    struct Sum(u64);
+
+    impl Monoid for Sum {
+        fn empty() -> Self {
+            Sum(0)
+        }
+
+        fn combine(&self, next: &Self) -> Self {
+            Sum(self.0 + next.0)
+        }
+    }
+
+    fn sum(chunk: &[u64]) -> Sum {
+        Sum(chunk.iter().sum())
+    }
+
+    // Running totals, each chunk carrying on from the total of the ones before it
+    fn running(prefix: &Sum, chunk: &[u64]) -> Vec<u64> {
+        chunk
+            .iter()
+            .scan(prefix.0, |total, x| {
+                *total += x;
+                Some(*total)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_sequential() {
+        let items: Vec<u64> = (0..100).map(|i| i * i % 37).collect();
+        let expected: Vec<u64> = running(&Sum::empty(), &items);
+
+        for chunk_size in [1, 2, 3, 7, 64, 100, 1000] {
+            let scanned: Vec<u64> = scan_chunks(&items, chunk_size, sum, running)
+                .into_iter()
+                .flatten()
+                .collect();
+            assert_eq!(scanned, expected, "chunks of {chunk_size}");
+            assert_eq!(reduce_chunks(&items, chunk_size, sum).0, expected[99]);
+        }
+        assert_eq!(scan(&items, sum, running).concat(), expected);
+        assert!(scan(&[], sum, running).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one item")]
+    fn zero_chunk_size() {
+        reduce_chunks(&[1u64], 0, sum);
+    }
+}
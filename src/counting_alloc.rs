@@ -0,0 +1,57 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+
+// Wraps the system allocator and counts every allocation made through it. A binary opts in with
+// #[global_allocator], otherwise the counters just stay at zero. Counting costs two relaxed atomic
+// adds per allocation, which every variant pays equally
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    // A growing Vec reallocates, so count it like a fresh allocation of the new size
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(new_size as u64, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Snapshot {
+    pub allocations: u64,
+    pub bytes: u64,
+}
+
+impl Snapshot {
+    pub fn since(&self, earlier: &Snapshot) -> Snapshot {
+        Snapshot {
+            allocations: self.allocations - earlier.allocations,
+            bytes: self.bytes - earlier.bytes,
+        }
+    }
+}
+
+pub fn snapshot() -> Snapshot {
+    Snapshot {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+    }
+}
+
+// Any real program has allocated something by the time it asks, so zero means the counter was
+// never installed
+pub fn installed() -> bool {
+    ALLOCATIONS.load(Ordering::Relaxed) > 0
+}
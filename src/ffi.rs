@@ -0,0 +1,136 @@
+// C interface to the registered solvers, built into the cdylib (target/release/libadvent_of_code.so)
+// with the header in include/aoc.h, generated from this file by cbindgen (the /// comments end up
+// in the header, see cbindgen.toml for the command).
+//
+// Answers are written into a caller owned buffer, snprintf style: the return value is the length
+// of the answer (without the NUL), if that is >= out_len the answer was cut short and the call can
+// be repeated with a bigger buffer. Negative returns are the AOC_ERR_* codes below. Inputs get the
+// same normalization as the runners (BOM, CRLF, trailing blank lines)
+use std::ffi::{CStr, c_char};
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+use crate::input::{self, Normalize};
+use crate::registry::{self, SOLVERS};
+
+/// No solver registered for that year/day/part/variant
+pub const AOC_ERR_NO_SOLVER: i64 = -1;
+/// Null pointer, or input/variant that isnt UTF-8
+pub const AOC_ERR_INVALID_ARGUMENT: i64 = -2;
+/// The solver panicked, usually on malformed input
+pub const AOC_ERR_SOLVER_FAILED: i64 = -3;
+
+/// Solves a part of the latest year with its baseline variant
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `out` to `out_len` writable bytes (`out` may be
+/// null if `out_len` is 0)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    len: usize,
+    out: *mut c_char,
+    out_len: usize,
+) -> i64 {
+    // SAFETY: same contract as this function, a null variant means the baseline
+    unsafe {
+        aoc_solve_variant(
+            registry::latest_year(),
+            day,
+            part,
+            std::ptr::null(),
+            input,
+            len,
+            out,
+            out_len,
+        )
+    }
+}
+
+/// Same as aoc_solve for any year and variant, `variant` is a NUL terminated name or null for the
+/// baseline
+///
+/// # Safety
+/// Same as aoc_solve, and `variant` must be null or a valid NUL terminated string
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_solve_variant(
+    year: u16,
+    day: u8,
+    part: u8,
+    variant: *const c_char,
+    input: *const u8,
+    len: usize,
+    out: *mut c_char,
+    out_len: usize,
+) -> i64 {
+    if input.is_null() && len > 0 {
+        return AOC_ERR_INVALID_ARGUMENT;
+    }
+
+    let variant = if variant.is_null() {
+        None
+    } else {
+        // SAFETY: caller guarantees a NUL terminated string
+        match unsafe { CStr::from_ptr(variant) }.to_str() {
+            Ok(name) => Some(name),
+            Err(_) => return AOC_ERR_INVALID_ARGUMENT,
+        }
+    };
+
+    let Some(solver) = registry::find(year, day, part, variant) else {
+        return AOC_ERR_NO_SOLVER;
+    };
+
+    let bytes = if len == 0 {
+        &[][..]
+    } else {
+        // SAFETY: caller guarantees `len` readable bytes
+        unsafe { slice::from_raw_parts(input, len) }
+    };
+    let Ok(contents) = std::str::from_utf8(bytes) else {
+        return AOC_ERR_INVALID_ARGUMENT;
+    };
+    let contents = input::normalize(contents.to_string(), Normalize::default());
+
+    // Unwinding into C is undefined behavior, so panics stop here
+    let Ok(answer) = panic::catch_unwind(AssertUnwindSafe(|| (solver.solve)(&contents))) else {
+        return AOC_ERR_SOLVER_FAILED;
+    };
+
+    // SAFETY: caller guarantees `out_len` writable bytes
+    unsafe { write_out(&answer, out, out_len) }
+}
+
+/// Number of registered solvers, for listing them with aoc_solver_name
+#[unsafe(no_mangle)]
+pub extern "C" fn aoc_solver_count() -> usize {
+    SOLVERS.len()
+}
+
+/// Writes the name of solver `index` ("2025/day3/p2/fast") into `out`, returns like aoc_solve
+///
+/// # Safety
+/// `out` must point to `out_len` writable bytes (or be null if `out_len` is 0)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_solver_name(index: usize, out: *mut c_char, out_len: usize) -> i64 {
+    match SOLVERS.get(index) {
+        // SAFETY: same contract as this function
+        Some(solver) => unsafe { write_out(&solver.name(), out, out_len) },
+        None => AOC_ERR_NO_SOLVER,
+    }
+}
+
+// Copies as much of `s` as fits plus a NUL, returns the full length
+unsafe fn write_out(s: &str, out: *mut c_char, out_len: usize) -> i64 {
+    if out_len > 0 && !out.is_null() {
+        let n = s.len().min(out_len - 1);
+        // SAFETY: n + 1 <= out_len and the caller guarantees that many writable bytes
+        unsafe {
+            std::ptr::copy_nonoverlapping(s.as_ptr(), out.cast::<u8>(), n);
+            *out.add(n) = 0;
+        }
+    }
+    s.len() as i64
+}
@@ -0,0 +1,50 @@
+// Random inputs shaped like the real puzzle inputs, for stress testing and benchmarking without
+// sharing anyone's personal input. Output only depends on (year, day, size, seed). The per day
+// generators live next to each year's solutions
+use crate::y2025;
+
+pub struct Rng(u64);
+
+impl Rng {
+    // Run the seed through splitmix64 first, xorshift gets stuck on a zero state and similar seeds
+    // would otherwise start out looking alike
+    pub fn new(seed: u64) -> Self {
+        let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        Rng((z ^ (z >> 31)) | 1)
+    }
+
+    // xorshift64, plenty random enough for puzzle data
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+}
+
+// Usual size for a day, roughly what the real input is
+pub fn default_size(year: u16, day: u8) -> Option<usize> {
+    match year {
+        2025 => y2025::generators::default_size(day),
+        _ => None,
+    }
+}
+
+pub fn generate(year: u16, day: u8, size: usize, seed: u64) -> Option<String> {
+    let mut rng = Rng::new(seed);
+
+    match year {
+        2025 => y2025::generators::generate(day, size, &mut rng),
+        _ => None,
+    }
+}
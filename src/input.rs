@@ -0,0 +1,236 @@
+use std::ops::{Deref, Range};
+use std::{fs, io, path::Path};
+
+use memmap2::Mmap;
+use sha2::{Digest, Sha256};
+
+// What to clean up before an input reaches a solver. Solvers are written against the inputs as
+// the website serves them (LF, no BOM, one trailing newline), so files saved on Windows or
+// pasted from an editor get turned back into that shape
+#[derive(Clone, Copy)]
+pub struct Normalize {
+    pub strip_bom: bool,
+    pub line_endings: bool,
+    pub trailing_blank_lines: bool,
+}
+
+impl Normalize {
+    // For solvers that want the file byte for byte
+    pub const RAW: Normalize = Normalize {
+        strip_bom: false,
+        line_endings: false,
+        trailing_blank_lines: false,
+    };
+
+    // Shared handling of the --raw and --keep-trailing flags for every runner
+    pub fn from_flags<S: AsRef<str>>(flags: &[S]) -> Normalize {
+        let has = |flag: &str| flags.iter().any(|f| f.as_ref() == flag);
+
+        if has("--raw") {
+            return Normalize::RAW;
+        }
+
+        Normalize {
+            trailing_blank_lines: !has("--keep-trailing"),
+            ..Normalize::default()
+        }
+    }
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Normalize {
+            strip_bom: true,
+            line_endings: true,
+            trailing_blank_lines: true,
+        }
+    }
+}
+
+pub fn normalize(mut contents: String, opts: Normalize) -> String {
+    if opts.strip_bom && contents.starts_with('\u{feff}') {
+        contents.drain(..'\u{feff}'.len_utf8());
+    }
+
+    // Only pay for the copy when there actually is a CR somewhere
+    if opts.line_endings && contents.contains('\r') {
+        contents = contents.replace("\r\n", "\n");
+    }
+
+    if opts.trailing_blank_lines {
+        // Cut everything after the newline that ends the last line with content. Trailing spaces
+        // on that line are kept since column based puzzles (day6) can care about them
+        let last = contents.trim_end().len();
+        match contents[last..].find('\n') {
+            Some(nl) if last > 0 => contents.truncate(last + nl + 1),
+            Some(_) => contents.clear(),
+            None => {}
+        }
+    }
+
+    contents
+}
+
+// Hex SHA-256 of an input, recorded next to every answer so it can be traced back to the exact
+// file it came from. Hash the normalized contents: that is what the solver saw, and for files
+// straight from the website it matches `sha256sum` anyway
+pub fn sha256(contents: &[u8]) -> String {
+    hex(&Sha256::digest(contents))
+}
+
+// sha256(normalize(input)) for an input that's only ever seen one line at a time, so streamed
+// inputs get the same hash as the same file read whole. Lines are given without their \n (the
+// caller drops the BOM and CRs if the options say so), `newline` says whether one followed.
+// Blank lines are held back in a second hasher until a line with content shows they werent
+// trailing, so memory stays constant however many there are
+pub struct LineHasher {
+    trim_trailing: bool,
+    // Everything up to and including the last line with content
+    committed: Sha256,
+    // `committed` plus the blank lines after it, if there are any
+    pending: Option<Sha256>,
+    pending_newline: bool,
+    any_content: bool,
+}
+
+impl LineHasher {
+    pub fn new(opts: Normalize) -> Self {
+        LineHasher {
+            trim_trailing: opts.trailing_blank_lines,
+            committed: Sha256::new(),
+            pending: None,
+            pending_newline: false,
+            any_content: false,
+        }
+    }
+
+    pub fn line(&mut self, line: &[u8], newline: bool) {
+        let hasher = if self.trim_trailing && is_blank(line) {
+            self.pending_newline |= newline;
+            self.pending.get_or_insert_with(|| self.committed.clone())
+        } else {
+            if let Some(pending) = self.pending.take() {
+                self.committed = pending;
+            }
+            self.pending_newline = false;
+            self.any_content = true;
+            &mut self.committed
+        };
+
+        hasher.update(line);
+        if newline {
+            hasher.update(b"\n");
+        }
+    }
+
+    pub fn finish(self) -> String {
+        // normalize cuts from the newline after the last content on, when theres no newline at
+        // all (a file of just spaces) nothing is cut
+        let hasher = match self.pending {
+            Some(pending) if !self.any_content && !self.pending_newline => pending,
+            _ => self.committed,
+        };
+        hex(&hasher.finalize())
+    }
+}
+
+// Same as a trim_end().is_empty() on the line, without validating UTF-8 for the usual lines
+// that start with something that isnt whitespace
+fn is_blank(line: &[u8]) -> bool {
+    !line
+        .iter()
+        .any(|b| b.is_ascii() && !b.is_ascii_whitespace())
+        && std::str::from_utf8(line).is_ok_and(|s| s.trim().is_empty())
+}
+
+fn hex(digest: &[u8]) -> String {
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub fn read<P: AsRef<Path>>(path: P, opts: Normalize) -> io::Result<String> {
+    fs::read_to_string(path).map(|contents| normalize(contents, opts))
+}
+
+// An input as raw bytes, memory mapped straight from the file where possible. For inputs too big
+// to comfortably read into a String: nothing is copied (unless CRLFs have to be rewritten) and
+// nothing is validated, solvers that take bytes never look at it as UTF-8
+pub struct Bytes {
+    storage: Storage,
+    // What's left after normalizing, BOM and trailing blank lines are cut off by narrowing this
+    range: Range<usize>,
+}
+
+enum Storage {
+    Mapped(Mmap),
+    Owned(Vec<u8>),
+}
+
+impl Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        let all: &[u8] = match &self.storage {
+            Storage::Mapped(map) => map,
+            Storage::Owned(vec) => vec,
+        };
+        &all[self.range.clone()]
+    }
+}
+
+// Same normalization as `read`, done on bytes
+pub fn read_bytes<P: AsRef<Path>>(path: P, opts: Normalize) -> io::Result<Bytes> {
+    let file = fs::File::open(path)?;
+
+    // Mapping an empty file fails on some platforms, and theres nothing to save anyway
+    let (storage, len) = if file.metadata()?.len() == 0 {
+        (Storage::Owned(Vec::new()), 0)
+    } else {
+        // SAFETY: the file could be changed by someone else while mapped, which is undefined
+        // behavior. Inputs arent written to while a solver runs, same as any tool reading them
+        let map = unsafe { Mmap::map(&file)? };
+        let len = map.len();
+        (Storage::Mapped(map), len)
+    };
+    let mut bytes = Bytes {
+        storage,
+        range: 0..len,
+    };
+
+    const BOM: &[u8] = "\u{feff}".as_bytes();
+    if opts.strip_bom && bytes.starts_with(BOM) {
+        bytes.range.start += BOM.len();
+    }
+
+    if opts.line_endings && bytes.contains(&b'\r') {
+        let mut owned = Vec::with_capacity(bytes.len());
+        let mut rest: &[u8] = &bytes;
+        while let Some(cr) = rest.iter().position(|&b| b == b'\r') {
+            owned.extend_from_slice(&rest[..cr]);
+            if rest.get(cr + 1) != Some(&b'\n') {
+                owned.push(b'\r');
+            }
+            rest = &rest[cr + 1..];
+        }
+        owned.extend_from_slice(rest);
+
+        bytes = Bytes {
+            range: 0..owned.len(),
+            storage: Storage::Owned(owned),
+        };
+    }
+
+    if opts.trailing_blank_lines {
+        // Same cut as for strings: keep the newline ending the last line with content
+        let last = bytes
+            .iter()
+            .rposition(|b| !b.is_ascii_whitespace())
+            .map_or(0, |i| i + 1);
+        match bytes[last..].iter().position(|&b| b == b'\n') {
+            Some(nl) if last > 0 => bytes.range.end = bytes.range.start + last + nl + 1,
+            Some(_) => bytes.range.end = bytes.range.start,
+            None => {}
+        }
+    }
+
+    Ok(bytes)
+}
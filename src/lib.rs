@@ -1,7 +1,22 @@
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod common;
+pub mod counting_alloc;
+pub mod ffi;
+pub mod generators;
+pub mod input;
+pub mod registry;
+pub mod scaling;
+pub mod streaming;
+pub mod trace;
+pub mod vis;
+pub mod y2025;
+
 #[macro_export]
 macro_rules! aoc_main {
-    ($p1:ident, $p2:ident, p1_alt = $p1_alt:ident, p2_alt:ident) => {
-        $crate::generate_main!($p1, $p2, $p1_alt, $p2_alt)
+    ($p1:ident, $p2:ident, p1_alt = $p1_alt:ident, p2_alt = $p2_alt:ident) => {
+        $crate::generate_main!($p1, $p2, $p1_alt, $p2_alt);
     };
 
     ($p1:ident, $p2:ident, p1_alt = $p1_alt:ident) => {
@@ -20,38 +35,54 @@ macro_rules! aoc_main {
 #[macro_export]
 macro_rules! generate_main {
     ($p1:ident, $p2:ident, $p1_run:ident, $p2_run:ident) => {
-        use std::fs;
         use std::time::Instant;
 
         fn main() -> Result<(), Box<dyn std::error::Error>> {
-            let path = std::env::args().nth(1).expect("No path given");
+            // Flags (--raw, --keep-trailing, --mmap, -v) can go anywhere, the rest are positional
+            let (flags, args): (Vec<String>, Vec<String>) =
+                std::env::args().partition(|a| a.starts_with('-'));
+
+            let path = args.get(1).expect("No path given");
 
-            let use_alt = std::env::args().nth(2).map(|s| s == "1").unwrap_or(false);
+            let use_alt = args.get(2).map(|s| s == "1").unwrap_or(false);
 
-            let times: usize = std::env::args()
-                .nth(3)
-                .unwrap_or("1".to_string())
+            let times: usize = args
+                .get(3)
+                .map(String::as_str)
+                .unwrap_or("1")
                 .parse()
                 .expect("Times NaN");
-            let part: usize = std::env::args()
-                .nth(4)
+            let part: usize = args
+                .get(4)
                 .expect("no part number")
                 .parse()
                 .expect("invalid part");
 
+            let normalize = $crate::input::Normalize::from_flags(&flags);
+            $crate::trace::init_from_flags(&flags);
+
+            // --mmap maps the file instead of copying it into a String, for huge generated inputs.
+            // These solvers take &str so it's still validated, aoc run --mmap is what skips that
             let start = Instant::now();
-            let contents = fs::read_to_string(path)?;
+            let (owned, mapped);
+            let contents: &str = if flags.iter().any(|f| f == "--mmap") {
+                mapped = $crate::input::read_bytes(path, normalize)?;
+                std::str::from_utf8(&mapped)?
+            } else {
+                owned = $crate::input::read(path, normalize)?;
+                &owned
+            };
             let io_end = start.elapsed();
 
             let mut total = String::new();
 
             for _ in 0..times {
                 total = match (part, use_alt) {
-                    (1, false) => $p1(&contents).to_string(),
-                    (1, true) => $p1_run(&contents).to_string(),
+                    (1, false) => $p1(contents).to_string(),
+                    (1, true) => $p1_run(contents).to_string(),
 
-                    (2, false) => $p2(&contents).to_string(),
-                    (2, true) => $p2_run(&contents).to_string(),
+                    (2, false) => $p2(contents).to_string(),
+                    (2, true) => $p2_run(contents).to_string(),
 
                     _ => panic!("Invalid part number (must be 1 or 2)"),
                 };
@@ -61,6 +92,10 @@ macro_rules! generate_main {
 
             println!("Part: {}, Alternative/Optim: {}", part, use_alt);
             println!("Result: {}", total);
+            println!(
+                "Input SHA-256: {}",
+                $crate::input::sha256(contents.as_bytes())
+            );
             println!("----------");
             println!("Total duration: {:?}", total_end);
             println!("IO duration:    {:?}", io_end);
@@ -0,0 +1,167 @@
+use crate::streaming;
+use crate::vis::Recorder;
+use crate::y2025;
+
+// A single runnable solution. Every part can have several variants (naive vs optimized, etc.)
+// which must all agree on the answer, the first one registered for a part is the baseline
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub variant: &'static str,
+    pub solve: fn(&str) -> String,
+    // Set for solvers that can work on raw bytes, so huge memory mapped inputs dont have to be
+    // validated as UTF-8 first
+    pub solve_bytes: Option<fn(&[u8]) -> String>,
+    // Set for solvers that fold over lines, so input of any size can be piped in (see streaming)
+    pub solve_stream: Option<streaming::Solve>,
+}
+
+impl Solver {
+    // Adds a streaming version, `F` folding over the lines the same way `solve` does
+    const fn streaming<F: streaming::LineFold>(mut self) -> Self {
+        self.solve_stream = Some(streaming::solve::<F>);
+        self
+    }
+
+    pub fn name(&self) -> String {
+        format!(
+            "{}/day{}/p{}/{}",
+            self.year, self.day, self.part, self.variant
+        )
+    }
+
+    // Solves raw bytes, only validating them when the solver needs a &str
+    pub fn solve_bytes(&self, input: &[u8]) -> Result<String, std::str::Utf8Error> {
+        match self.solve_bytes {
+            Some(solve) => Ok(solve(input)),
+            None => std::str::from_utf8(input).map(self.solve),
+        }
+    }
+}
+
+// Wraps a solve function so every solver has the same signature no matter what it returns
+macro_rules! solver {
+    ($year:literal, $day:literal, $part:literal, $variant:literal, $solve:expr) => {
+        Solver {
+            year: $year,
+            day: $day,
+            part: $part,
+            variant: $variant,
+            solve: |input| $solve(input).to_string(),
+            solve_bytes: None,
+            solve_stream: None,
+        }
+    };
+    // For solvers taking &[u8], the &str version just hands over the bytes
+    ($year:literal, $day:literal, $part:literal, $variant:literal, bytes $solve:expr) => {
+        Solver {
+            year: $year,
+            day: $day,
+            part: $part,
+            variant: $variant,
+            solve: |input| $solve(input.as_bytes()).to_string(),
+            solve_bytes: Some(|input| $solve(input).to_string()),
+            solve_stream: None,
+        }
+    };
+}
+
+pub static SOLVERS: &[Solver] = &[
+    solver!(2025, 1, 1, "default", bytes y2025::day1::solve_p1_bytes)
+        .streaming::<y2025::day1::LandsOnZero>(),
+    solver!(2025, 1, 1, "simulated", y2025::day1::solve_p1_simulated),
+    solver!(2025, 1, 1, "scan", y2025::day1::solve_p1_scan),
+    solver!(2025, 1, 1, "prefix", y2025::day1::solve_p1_prefix),
+    solver!(2025, 1, 2, "default", bytes y2025::day1::solve_p2_bytes)
+        .streaming::<y2025::day1::PassesZero>(),
+    solver!(2025, 1, 2, "simulated", y2025::day1::solve_p2_simulated),
+    solver!(2025, 1, 2, "scan", y2025::day1::solve_p2_scan),
+    solver!(2025, 1, 2, "prefix", y2025::day1::solve_p2_prefix),
+    solver!(2025, 2, 1, "default", bytes y2025::day2::solve_p1_bytes),
+    solver!(2025, 2, 1, "brute", y2025::day2::solve_p1_brute),
+    solver!(2025, 2, 1, "u128", bytes y2025::day2::solve_p1_in::<u128>),
+    #[cfg(feature = "bignum")]
+    solver!(2025, 2, 1, "bignum", bytes y2025::day2::solve_p1_in::<num_bigint::BigUint>),
+    solver!(2025, 2, 2, "default", bytes y2025::day2::solve_p2_bytes),
+    solver!(2025, 2, 2, "brute", y2025::day2::solve_p2_brute),
+    solver!(2025, 2, 2, "listed", y2025::day2::solve_p2_listed),
+    solver!(2025, 2, 2, "u128", bytes y2025::day2::solve_p2_in::<u128>),
+    #[cfg(feature = "bignum")]
+    solver!(2025, 2, 2, "bignum", bytes y2025::day2::solve_p2_in::<num_bigint::BigUint>),
+    solver!(2025, 3, 1, "default", bytes y2025::day3::solve_p1_bytes)
+        .streaming::<y2025::day3::StreamP1>(),
+    solver!(2025, 3, 2, "naive", bytes y2025::day3::solve_p2_bytes)
+        .streaming::<y2025::day3::StreamP2<y2025::day3::NaiveIndex>>(),
+    solver!(2025, 3, 2, "fast", bytes y2025::day3::solve_p2_fast_bytes)
+        .streaming::<y2025::day3::StreamP2<y2025::day3::FastIndex>>(),
+    solver!(2025, 4, 1, "naive", |input| y2025::day4::solve_p1(input)
+        .expect("No total")),
+    solver!(2025, 4, 1, "flattened", y2025::day4::solve_p1_flattened),
+    solver!(2025, 4, 2, "default", y2025::day4::solve_p2),
+    solver!(2025, 5, 1, "default", bytes y2025::day5::solve_p1_bytes)
+        .streaming::<y2025::day5::StreamP1>(),
+    solver!(2025, 5, 2, "default", bytes y2025::day5::solve_p2_bytes)
+        .streaming::<y2025::day5::StreamP2>(),
+    solver!(2025, 6, 1, "default", y2025::day6::solve_p1),
+    solver!(2025, 6, 2, "default", y2025::day6::solve_p2),
+    solver!(2025, 7, 1, "default", y2025::day7::solve_p1),
+    solver!(2025, 7, 2, "default", y2025::day7::solve_p2),
+    solver!(2025, 8, 1, "default", y2025::day8::solve_p1),
+    solver!(2025, 8, 2, "default", y2025::day8::solve_p2),
+];
+
+// All variants of a part, baseline first
+pub fn variants(year: u16, day: u8, part: u8) -> impl Iterator<Item = &'static Solver> {
+    SOLVERS
+        .iter()
+        .filter(move |s| s.year == year && s.day == day && s.part == part)
+}
+
+// Looks up a variant by name, or the baseline when no name is given
+pub fn find(year: u16, day: u8, part: u8, variant: Option<&str>) -> Option<&'static Solver> {
+    variants(year, day, part).find(|s| variant.is_none_or(|v| v == s.variant))
+}
+
+pub fn years() -> impl Iterator<Item = u16> {
+    let mut years: Vec<u16> = SOLVERS.iter().map(|s| s.year).collect();
+    years.sort_unstable();
+    years.dedup();
+    years.into_iter()
+}
+
+// The year commands default to when none is given
+pub fn latest_year() -> u16 {
+    years().last().expect("no solvers registered")
+}
+
+pub fn days(year: u16) -> impl Iterator<Item = u8> {
+    let mut days: Vec<u8> = SOLVERS
+        .iter()
+        .filter(|s| s.year == year)
+        .map(|s| s.day)
+        .collect();
+    days.sort_unstable();
+    days.dedup();
+    days.into_iter()
+}
+
+// Days that can replay their solution as frames for `aoc vis`
+pub struct Visualizer {
+    pub year: u16,
+    pub day: u8,
+    pub visualize: fn(&str, &mut Recorder),
+}
+
+pub static VISUALIZERS: &[Visualizer] = &[
+    Visualizer {
+        year: 2025,
+        day: 4,
+        visualize: y2025::day4::visualize_p2,
+    },
+    Visualizer {
+        year: 2025,
+        day: 7,
+        visualize: y2025::day7::visualize_beams,
+    },
+];
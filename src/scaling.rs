@@ -0,0 +1,144 @@
+// Fits measured (input size, time) pairs to candidate growth curves, to check what complexity a
+// solver actually has in practice
+use std::fmt::Write;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Model {
+    Linear,
+    Linearithmic,
+    Quadratic,
+}
+
+impl Model {
+    pub const ALL: [Model; 3] = [Model::Linear, Model::Linearithmic, Model::Quadratic];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Model::Linear => "n",
+            Model::Linearithmic => "n log n",
+            Model::Quadratic => "n^2",
+        }
+    }
+
+    fn eval(&self, n: f64) -> f64 {
+        match self {
+            Model::Linear => n,
+            Model::Linearithmic => n * n.log2(),
+            Model::Quadratic => n * n,
+        }
+    }
+}
+
+pub struct Fit {
+    pub model: Model,
+    // t ~= scale * f(n)
+    pub scale: f64,
+    // RMS of the log error, so a fit is judged on ratios and the biggest sizes dont drown out
+    // the small ones
+    pub error: f64,
+}
+
+impl Fit {
+    pub fn predict(&self, n: f64) -> f64 {
+        self.scale * self.model.eval(n)
+    }
+}
+
+// Fits every model, best (lowest error) first. Points are (n, seconds)
+pub fn fit(points: &[(f64, f64)]) -> Vec<Fit> {
+    let mut fits: Vec<Fit> = Model::ALL
+        .iter()
+        .map(|&model| {
+            // In log space the model is log t = log c + log f(n), so the best log c is just the
+            // mean difference and the error is whatever spread is left
+            let diffs: Vec<f64> = points
+                .iter()
+                .map(|&(n, t)| t.ln() - model.eval(n).ln())
+                .collect();
+            let log_scale = diffs.iter().sum::<f64>() / diffs.len() as f64;
+            let error = (diffs.iter().map(|d| (d - log_scale).powi(2)).sum::<f64>()
+                / diffs.len() as f64)
+                .sqrt();
+
+            Fit {
+                model,
+                scale: log_scale.exp(),
+                error,
+            }
+        })
+        .collect();
+
+    fits.sort_by(|a, b| a.error.total_cmp(&b.error));
+    fits
+}
+
+// Slope of the least squares line through the log-log points, i.e. the k in t ~ n^k
+pub fn exponent(points: &[(f64, f64)]) -> f64 {
+    let logs: Vec<(f64, f64)> = points.iter().map(|&(n, t)| (n.ln(), t.ln())).collect();
+    let len = logs.len() as f64;
+    let mean_x = logs.iter().map(|p| p.0).sum::<f64>() / len;
+    let mean_y = logs.iter().map(|p| p.1).sum::<f64>() / len;
+
+    let cov: f64 = logs.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let var: f64 = logs.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    cov / var
+}
+
+// Log-log plot of the measurements (*) over the fitted curve (.)
+pub fn ascii_plot(points: &[(f64, f64)], fit: &Fit, width: usize, height: usize) -> String {
+    let (min_x, max_x) = bounds(points.iter().map(|p| p.0.ln()));
+    let curve: Vec<(f64, f64)> = (0..width)
+        .map(|col| {
+            let x = min_x + (max_x - min_x) * col as f64 / (width - 1) as f64;
+            (x, fit.predict(x.exp()).ln())
+        })
+        .collect();
+    let (min_y, max_y) = bounds(
+        points
+            .iter()
+            .map(|p| p.1.ln())
+            .chain(curve.iter().map(|p| p.1)),
+    );
+
+    let to_cell = |x: f64, y: f64| {
+        let col = ((x - min_x) / (max_x - min_x).max(f64::EPSILON) * (width - 1) as f64).round();
+        let row = ((max_y - y) / (max_y - min_y).max(f64::EPSILON) * (height - 1) as f64).round();
+        (col as usize, row as usize)
+    };
+
+    let mut grid = vec![vec![' '; width]; height];
+    for &(x, y) in &curve {
+        let (col, row) = to_cell(x, y);
+        grid[row][col] = '.';
+    }
+    for &(n, t) in points {
+        let (col, row) = to_cell(n.ln(), t.ln());
+        grid[row][col] = '*';
+    }
+
+    let mut out = String::new();
+    writeln!(out, "time (log) {:>10}", format_secs(max_y.exp())).unwrap();
+    for row in grid {
+        writeln!(out, "  |{}", row.into_iter().collect::<String>()).unwrap();
+    }
+    writeln!(out, "  +{}", "-".repeat(width)).unwrap();
+    writeln!(
+        out,
+        "   n = {:<12} (log scale) {:>width$}",
+        min_x.exp().round(),
+        max_x.exp().round(),
+        width = width.saturating_sub(26)
+    )
+    .unwrap();
+    out
+}
+
+fn bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+        (lo.min(v), hi.max(v))
+    })
+}
+
+fn format_secs(secs: f64) -> String {
+    format!("{:?}", std::time::Duration::from_secs_f64(secs))
+}
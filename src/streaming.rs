@@ -0,0 +1,159 @@
+// Solvers that fold over the input one line at a time, so they can run on input piped in from a
+// generator (or anything else too big to hold) in constant memory. Optional, days that need the
+// whole input at once (grids, sorting every edge) just dont implement it
+use std::fmt::Display;
+use std::io::{self, BufRead};
+use std::ops::ControlFlow;
+
+use crate::common::parse;
+use crate::input::{LineHasher, Normalize};
+
+pub trait LineFold: Default {
+    type Output: Display;
+
+    // Called with every line, without the line ending. Break stops reading early, for folds that
+    // already have their answer (e.g. day5 part 2 never needs the ids)
+    fn line(&mut self, line: &[u8]) -> ControlFlow<()>;
+
+    fn finish(self) -> Self::Output;
+}
+
+// Runs a fold over a reader, reusing one line buffer. The fold sees the same lines fold_bytes
+// would on the input normalized with `opts`: the BOM is only dropped if they say so, CRs before
+// newlines always are (like parse::lines). Trailing blank lines are passed on, folds that dont
+// expect blank lines should skip them. Also returns the SHA-256 of the normalized input (see
+// input::LineHasher), so the rest is still read after a fold breaks early
+pub fn fold_lines<F: LineFold>(
+    reader: &mut dyn BufRead,
+    opts: Normalize,
+) -> io::Result<(F::Output, String)> {
+    const BOM: &[u8] = "\u{feff}".as_bytes();
+
+    let mut fold = F::default();
+    let mut hasher = LineHasher::new(opts);
+    let mut folding = true;
+    let mut buf = Vec::new();
+    let mut first = true;
+
+    loop {
+        buf.clear();
+        if reader.read_until(b'\n', &mut buf)? == 0 {
+            break;
+        }
+
+        let mut line = &buf[..];
+        if first && opts.strip_bom {
+            line = line.strip_prefix(BOM).unwrap_or(line);
+            first = false;
+        }
+        let newline = line.ends_with(b"\n");
+        line = line.strip_suffix(b"\n").unwrap_or(line);
+
+        // A CR only counts as part of the line ending when a newline follows, like normalize
+        // does. The fold never sees one either way
+        let crlf = line.strip_suffix(b"\r");
+        let hashed = match crlf {
+            Some(stripped) if newline && opts.line_endings => stripped,
+            _ => line,
+        };
+        hasher.line(hashed, newline);
+        line = crlf.unwrap_or(line);
+
+        if folding && fold.line(line).is_break() {
+            folding = false;
+        }
+    }
+
+    Ok((fold.finish(), hasher.finish()))
+}
+
+// Runs a fold over an input that's already in memory, for days whose whole-input solver is the
+// same fold
+pub fn fold_bytes<F: LineFold>(input: &[u8]) -> F::Output {
+    let mut fold = F::default();
+    for line in parse::lines(input) {
+        if fold.line(line).is_break() {
+            break;
+        }
+    }
+    fold.finish()
+}
+
+// What a streamed solve prints, the answer and the hash of the input it came from
+pub struct Streamed {
+    pub answer: String,
+    pub sha256: String,
+}
+
+// Same signature for every fold, for the registry
+pub type Solve = fn(&mut dyn BufRead, Normalize) -> io::Result<Streamed>;
+
+pub fn solve<F: LineFold>(reader: &mut dyn BufRead, opts: Normalize) -> io::Result<Streamed> {
+    let (answer, sha256) = fold_lines::<F>(reader, opts)?;
+    Ok(Streamed {
+        answer: answer.to_string(),
+        sha256,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input;
+
+    // Every line it was given, separated by |
+    #[derive(Default)]
+    struct Lines(Vec<String>);
+
+    impl LineFold for Lines {
+        type Output = String;
+
+        fn line(&mut self, line: &[u8]) -> ControlFlow<()> {
+            self.0.push(String::from_utf8_lossy(line).into_owned());
+            ControlFlow::Continue(())
+        }
+
+        fn finish(self) -> String {
+            self.0.join("|")
+        }
+    }
+
+    // Same hash as normalizing the whole input with the same flags
+    #[test]
+    fn hash_matches_normalize() {
+        let inputs = [
+            "a\nb\n",
+            "a\nb",
+            "\u{feff}a\r\nb\r\n\r\n\n",
+            "a\n  \n\nb\n \n\n",
+            "a\r",
+            "  \n\n",
+            "  ",
+            "",
+        ];
+        let flags: [&[&str]; 3] = [&[], &["--raw"], &["--keep-trailing"]];
+
+        for input in inputs {
+            for flags in flags {
+                let opts = Normalize::from_flags(flags);
+                let expected = input::sha256(input::normalize(input.to_string(), opts).as_bytes());
+                let (_, sha256) = fold_lines::<Lines>(&mut input.as_bytes(), opts).unwrap();
+                assert_eq!(sha256, expected, "{input:?} with {flags:?}");
+            }
+        }
+    }
+
+    // CRs are dropped like parse::lines does, the BOM only when normalizing
+    #[test]
+    fn folded_lines() {
+        let input = "\u{feff}a\r\nb\r\n\n";
+        let fold = |flags: &[&str]| {
+            fold_lines::<Lines>(&mut input.as_bytes(), Normalize::from_flags(flags))
+                .unwrap()
+                .0
+        };
+        assert_eq!(fold(&[]), "a|b|");
+        assert_eq!(fold(&["--raw"]), "\u{feff}a|b|");
+        assert_eq!(fold_bytes::<Lines>(input.as_bytes()), fold(&["--raw"]));
+    }
+}
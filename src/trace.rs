@@ -0,0 +1,62 @@
+use std::fmt::Debug;
+use std::io::Write;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// 0 = silent, 1 = -v (summaries), 2 = -vv (every event)
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+// Picks the verbosity out of the runner flags (-v / -vv). Kept outside the feature gate so the
+// runners dont need cfgs of their own, a build without the feature just warns that nothing will
+// be printed
+pub fn init_from_flags<S: AsRef<str>>(flags: &[S]) {
+    let level = flags
+        .iter()
+        .map(|f| match f.as_ref() {
+            "-v" => 1,
+            "-vv" => 2,
+            _ => 0,
+        })
+        .max()
+        .unwrap_or(0);
+
+    if level > 0 && !cfg!(feature = "trace") {
+        eprintln!("warning: -v needs a build with `--features trace`, no events will be shown");
+    }
+
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn enabled(level: u8) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= level
+}
+
+// Events go to stderr as `trace <event> key=value ...` so they never mix with the answer
+pub fn emit(event: &str, fields: &[(&str, &dyn Debug)]) {
+    let mut line = format!("trace {}", event);
+    for (key, value) in fields {
+        line.push_str(&format!(" {}={:?}", key, value));
+    }
+
+    let _ = writeln!(std::io::stderr().lock(), "{}", line);
+}
+
+// trace!(level, "event", key = value, ...)
+// Without the trace feature this expands to nothing, so the arguments are never even evaluated
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! trace {
+    ($level:expr, $event:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled($level) {
+            $crate::trace::emit(
+                $event,
+                &[$((stringify!($key), &$value as &dyn std::fmt::Debug)),*],
+            );
+        }
+    };
+}
+
+#[cfg(not(feature = "trace"))]
+#[macro_export]
+macro_rules! trace {
+    ($($tokens:tt)*) => {};
+}
@@ -0,0 +1,138 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+// One snapshot of a grid puzzle. Cells are the puzzle's own bytes ('@', '^', '|', ...) so the
+// solver doesnt have to know how it gets drawn, highlight holds indices into cells that changed
+// in this step (removed rolls, splitters hit, ...)
+#[derive(Clone)]
+pub struct Frame {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<u8>,
+    pub highlight: Vec<usize>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, cells: Vec<u8>) -> Self {
+        assert_eq!(cells.len(), width * height, "cells dont match frame size");
+        Frame {
+            width,
+            height,
+            cells,
+            highlight: Vec::new(),
+        }
+    }
+
+    pub fn with_highlight(mut self, highlight: Vec<usize>) -> Self {
+        self.highlight = highlight;
+        self
+    }
+}
+
+// Solvers push frames here as they go, the runner decides how to render them afterwards
+#[derive(Default)]
+pub struct Recorder {
+    pub frames: Vec<Frame>,
+}
+
+impl Recorder {
+    pub fn push(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+}
+
+// Colors shared by both renderers
+const HIGHLIGHT: [u8; 3] = [230, 50, 50];
+
+fn color(cell: u8) -> [u8; 3] {
+    match cell {
+        b'.' => [20, 20, 30],
+        b'@' => [230, 230, 230],
+        b'^' => [210, 160, 40],
+        b'|' => [80, 200, 255],
+        b'S' => [60, 220, 60],
+        _ => [120, 120, 120],
+    }
+}
+
+// Resolve every cell's color up front so highlights are one pass instead of a lookup per cell
+fn colors(frame: &Frame) -> Vec<[u8; 3]> {
+    let mut colors: Vec<[u8; 3]> = frame.cells.iter().map(|&c| color(c)).collect();
+    for &i in &frame.highlight {
+        colors[i] = HIGHLIGHT;
+    }
+    colors
+}
+
+// Plays the frames in the terminal using 24 bit color, redrawing over the previous frame
+pub fn render_ansi<W: Write>(frames: &[Frame], delay: Duration, out: &mut W) -> io::Result<()> {
+    write!(out, "\x1b[2J")?;
+
+    for (n, frame) in frames.iter().enumerate() {
+        let colors = colors(frame);
+        let mut screen = String::with_capacity(frame.cells.len() * 20);
+        screen.push_str("\x1b[H");
+
+        for y in 0..frame.height {
+            for x in 0..frame.width {
+                let i = y * frame.width + x;
+                let [r, g, b] = colors[i];
+                screen.push_str(&format!(
+                    "\x1b[38;2;{};{};{}m{}",
+                    r, g, b, frame.cells[i] as char
+                ));
+            }
+            screen.push_str("\x1b[0m\n");
+        }
+        screen.push_str(&format!("frame {}/{}\n", n + 1, frames.len()));
+
+        out.write_all(screen.as_bytes())?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+
+    Ok(())
+}
+
+// Binary PPM (P6), every cell drawn as a scale x scale square
+pub fn write_ppm<W: Write>(frame: &Frame, scale: usize, out: &mut W) -> io::Result<()> {
+    write!(
+        out,
+        "P6\n{} {}\n255\n",
+        frame.width * scale,
+        frame.height * scale
+    )?;
+
+    let colors = colors(frame);
+    let mut row = Vec::with_capacity(frame.width * scale * 3);
+    for line in colors.chunks(frame.width) {
+        row.clear();
+        for rgb in line {
+            for _ in 0..scale {
+                row.extend_from_slice(rgb);
+            }
+        }
+        for _ in 0..scale {
+            out.write_all(&row)?;
+        }
+    }
+
+    Ok(())
+}
+
+// Writes frame_00000.ppm, frame_00001.ppm, ... into dir (stitch with e.g. ffmpeg)
+pub fn write_ppm_sequence(frames: &[Frame], dir: &Path, scale: usize) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+
+    for (n, frame) in frames.iter().enumerate() {
+        let file = File::create(dir.join(format!("frame_{:05}.ppm", n)))?;
+        let mut out = BufWriter::new(file);
+        write_ppm(frame, scale, &mut out)?;
+        out.flush()?;
+    }
+
+    Ok(())
+}
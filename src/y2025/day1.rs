@@ -0,0 +1,499 @@
+use std::ops::ControlFlow;
+
+use crate::common::parse;
+use crate::common::scan::{self, Monoid};
+use crate::streaming::{self, LineFold};
+
+// The safe's dial has the numbers 0 to 99 and starts out pointing at 50
+const DIAL_SIZE: u64 = 100;
+const START: u64 = 50;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rotation {
+    // Towards lower numbers
+    Left(u64),
+    Right(u64),
+}
+
+impl Rotation {
+    // L or R followed by how many clicks to turn
+    pub fn parse(line: &[u8]) -> Rotation {
+        let (&direction, clicks) = line.split_first().expect("Empty rotation");
+        let clicks = parse::unsigned(clicks).unwrap();
+        match direction {
+            b'L' => Rotation::Left(clicks),
+            b'R' => Rotation::Right(clicks),
+            _ => panic!("Unknown direction"),
+        }
+    }
+}
+
+// One rotation per line, blank lines are skipped
+pub fn rotations(input: &str) -> impl Iterator<Item = Rotation> + '_ {
+    parse::lines(input.as_bytes())
+        .filter(|line| !line.is_empty())
+        .map(Rotation::parse)
+}
+
+// A dial with numbers 0..size that keeps count of how often it hit 0. Crossings are every click
+// that lands on 0 (part 2), landings are rotations that end on it (part 1)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Dial {
+    size: u64,
+    position: u64,
+    crossings: u64,
+    landings: u64,
+}
+
+// Where the dial is after an instruction, and how many times that instruction hit 0
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Step {
+    pub index: usize,
+    pub position: u64,
+    pub crossings: u64,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial::new(DIAL_SIZE, START)
+    }
+}
+
+impl Dial {
+    pub fn new(size: u64, start: u64) -> Self {
+        assert!(start < size, "Start has to be on the dial");
+        Dial {
+            size,
+            position: start,
+            crossings: 0,
+            landings: 0,
+        }
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn crossings(&self) -> u64 {
+        self.crossings
+    }
+
+    pub fn landings(&self) -> u64 {
+        self.landings
+    }
+
+    // Turns the dial, returns how many clicks landed on 0 along the way
+    pub fn rotate(&mut self, rotation: Rotation) -> u64 {
+        let (dial, size) = (self.position, self.size);
+        let clicks = match rotation {
+            Rotation::Left(clicks) | Rotation::Right(clicks) => clicks,
+        };
+
+        // Every full turn passes 0 exactly once, after that only the remainder can reach it
+        let mut zeros = clicks / size;
+        let clicks = clicks % size;
+
+        self.position = match rotation {
+            Rotation::Left(_) => {
+                // Starting on 0 doesnt count, we'd be moving away from it
+                if dial != 0 && clicks >= dial {
+                    zeros += 1;
+                }
+                (dial + size - clicks) % size
+            }
+            Rotation::Right(_) => {
+                if dial + clicks >= size {
+                    zeros += 1;
+                }
+                (dial + clicks) % size
+            }
+        };
+
+        self.crossings += zeros;
+        self.landings += (self.position == 0) as u64;
+        zeros
+    }
+
+    // Applies the rotations lazily, yielding the dial's state after each one. The dial moves along
+    // with the iterator, so it's wherever the last rotation pulled out of it left it
+    pub fn steps<I>(&mut self, rotations: I) -> impl Iterator<Item = Step>
+    where
+        I: IntoIterator<Item = Rotation>,
+    {
+        rotations
+            .into_iter()
+            .enumerate()
+            .map(move |(index, rotation)| {
+                let crossings = self.rotate(rotation);
+                Step {
+                    index,
+                    position: self.position,
+                    crossings,
+                }
+            })
+    }
+
+    // Index of the first instruction that hit 0, if any did. Stops turning right after it
+    pub fn first_crossing<I>(&mut self, rotations: I) -> Option<usize>
+    where
+        I: IntoIterator<Item = Rotation>,
+    {
+        self.steps(rotations)
+            .find(|step| step.crossings > 0)
+            .map(|step| step.index)
+    }
+
+    // How many instructions left the dial resting on `position`, the start counts as well. Turns
+    // through all of them
+    pub fn time_on<I>(&mut self, rotations: I, position: u64) -> usize
+    where
+        I: IntoIterator<Item = Rotation>,
+    {
+        let start = (self.position == position) as usize;
+        start
+            + self
+                .steps(rotations)
+                .filter(|step| step.position == position)
+                .count()
+    }
+}
+
+// Both parts are the same walk, they just read a different counter at the end
+#[derive(Default)]
+pub(crate) struct LandsOnZero(Dial);
+
+impl LineFold for LandsOnZero {
+    type Output = u64;
+
+    fn line(&mut self, line: &[u8]) -> ControlFlow<()> {
+        if !line.is_empty() {
+            self.0.rotate(Rotation::parse(line));
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self) -> u64 {
+        self.0.landings()
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct PassesZero(Dial);
+
+impl LineFold for PassesZero {
+    type Output = u64;
+
+    fn line(&mut self, line: &[u8]) -> ControlFlow<()> {
+        if !line.is_empty() {
+            self.0.rotate(Rotation::parse(line));
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self) -> u64 {
+        self.0.crossings()
+    }
+}
+
+pub fn solve_p1(rotations: &str) -> u64 {
+    solve_p1_bytes(rotations.as_bytes())
+}
+
+pub fn solve_p1_bytes(rotations: &[u8]) -> u64 {
+    streaming::fold_bytes::<LandsOnZero>(rotations)
+}
+
+pub fn solve_p2(rotations: &str) -> u64 {
+    solve_p2_bytes(rotations.as_bytes())
+}
+
+pub fn solve_p2_bytes(rotations: &[u8]) -> u64 {
+    streaming::fold_bytes::<PassesZero>(rotations)
+}
+
+// What a run of rotations does to the dial, for every position it could start on. The dial always
+// moves by the same amount no matter where it starts, but how often it hits 0 depends on the start
+const SIZE: usize = DIAL_SIZE as usize;
+
+#[derive(PartialEq, Eq, Debug)]
+struct Walk {
+    // Ends on (start + shift) % DIAL_SIZE
+    shift: u64,
+    landings: [u64; SIZE],
+    crossings: [u64; SIZE],
+}
+
+impl Walk {
+    fn new(rotations: &[Rotation]) -> Self {
+        // Difference arrays over the start position, every rotation only touches one or two runs
+        // of starts so a chunk is O(rotations + DIAL_SIZE) instead of O(rotations * DIAL_SIZE)
+        let mut landings = [0i64; SIZE + 1];
+        let mut crossings = [0i64; SIZE + 1];
+        let mut full_turns = 0;
+        let mut shift = 0;
+
+        // Adds 1 to starts that are at dial positions first..=last right now
+        let add = |diff: &mut [i64; SIZE + 1], first: u64, last: u64, shift: u64| {
+            let lo = (first + DIAL_SIZE - shift) % DIAL_SIZE;
+            let hi = lo + (last - first);
+            if hi < DIAL_SIZE {
+                diff[lo as usize] += 1;
+                diff[hi as usize + 1] -= 1;
+            } else {
+                diff[lo as usize] += 1;
+                diff[SIZE] -= 1;
+                diff[0] += 1;
+                diff[(hi - DIAL_SIZE) as usize + 1] -= 1;
+            }
+        };
+
+        for &rotation in rotations {
+            let (Rotation::Left(clicks) | Rotation::Right(clicks)) = rotation;
+            full_turns += clicks / DIAL_SIZE;
+            let clicks = clicks % DIAL_SIZE;
+
+            // Same conditions as Dial::rotate, as ranges of the current position
+            if clicks > 0 {
+                match rotation {
+                    Rotation::Left(_) => add(&mut crossings, 1, clicks, shift),
+                    Rotation::Right(_) => {
+                        add(&mut crossings, DIAL_SIZE - clicks, DIAL_SIZE - 1, shift)
+                    }
+                }
+            }
+
+            shift = match rotation {
+                Rotation::Left(_) => (shift + DIAL_SIZE - clicks) % DIAL_SIZE,
+                Rotation::Right(_) => (shift + clicks) % DIAL_SIZE,
+            };
+            add(&mut landings, 0, 0, shift);
+        }
+
+        let mut walk = Walk {
+            shift,
+            landings: [0; SIZE],
+            crossings: [0; SIZE],
+        };
+        let (mut landed, mut crossed) = (0, 0);
+        for start in 0..SIZE {
+            landed += landings[start];
+            crossed += crossings[start];
+            walk.landings[start] = landed as u64;
+            walk.crossings[start] = crossed as u64 + full_turns;
+        }
+        walk
+    }
+}
+
+impl Monoid for Walk {
+    fn empty() -> Self {
+        Walk {
+            shift: 0,
+            landings: [0; SIZE],
+            crossings: [0; SIZE],
+        }
+    }
+
+    fn combine(&self, next: &Self) -> Self {
+        let mut walk = Walk {
+            shift: (self.shift + next.shift) % DIAL_SIZE,
+            ..Walk::empty()
+        };
+        for start in 0..SIZE {
+            // `next` starts where `self` left the dial
+            let mid = (start + self.shift as usize) % SIZE;
+            walk.landings[start] = self.landings[start] + next.landings[mid];
+            walk.crossings[start] = self.crossings[start] + next.crossings[mid];
+        }
+        walk
+    }
+}
+
+fn scan_walk(input: &str) -> Walk {
+    let rotations: Vec<_> = rotations(input).collect();
+    scan::reduce(&rotations, Walk::new)
+}
+
+pub fn solve_p1_scan(rotations: &str) -> u64 {
+    scan_walk(rotations).landings[START as usize]
+}
+
+pub fn solve_p2_scan(rotations: &str) -> u64 {
+    scan_walk(rotations).crossings[START as usize]
+}
+
+// Where the dial ends up after a run of rotations relative to where it started. Thats all the two
+// pass scan needs to know where each chunk starts, the chunks are then turned for real
+struct Shift(u64);
+
+impl Shift {
+    fn new(rotations: &[Rotation]) -> Self {
+        Shift(rotations.iter().fold(0, |shift, rotation| match rotation {
+            Rotation::Left(clicks) => (shift + DIAL_SIZE - clicks % DIAL_SIZE) % DIAL_SIZE,
+            Rotation::Right(clicks) => (shift + clicks) % DIAL_SIZE,
+        }))
+    }
+}
+
+impl Monoid for Shift {
+    fn empty() -> Self {
+        Shift(0)
+    }
+
+    fn combine(&self, next: &Self) -> Self {
+        Shift((self.0 + next.0) % DIAL_SIZE)
+    }
+}
+
+// (landings, crossings) of one chunk, given how far the chunks before it moved the dial
+fn turn_chunk(before: &Shift, rotations: &[Rotation]) -> (u64, u64) {
+    let mut dial = Dial::new(DIAL_SIZE, (START + before.0) % DIAL_SIZE);
+    for &rotation in rotations {
+        dial.rotate(rotation);
+    }
+    (dial.landings(), dial.crossings())
+}
+
+fn prefix_walk(input: &str) -> (u64, u64) {
+    let rotations: Vec<_> = rotations(input).collect();
+    scan::scan(&rotations, Shift::new, turn_chunk)
+        .into_iter()
+        .fold((0, 0), |(landed, passed), (l, p)| (landed + l, passed + p))
+}
+
+pub fn solve_p1_prefix(rotations: &str) -> u64 {
+    prefix_walk(rotations).0
+}
+
+pub fn solve_p2_prefix(rotations: &str) -> u64 {
+    prefix_walk(rotations).1
+}
+
+// Turns the dial one click at a time, slow but obviously right, to cross-check the math above.
+// Returns (rotations ending on 0, clicks landing on 0)
+fn simulate(input: &str) -> (u64, u64) {
+    let mut dial = START;
+    let (mut landed, mut passed) = (0, 0);
+
+    for rotation in rotations(input) {
+        let (step, clicks) = match rotation {
+            Rotation::Left(clicks) => (DIAL_SIZE - 1, clicks),
+            Rotation::Right(clicks) => (1, clicks),
+        };
+
+        for _ in 0..clicks {
+            dial = (dial + step) % DIAL_SIZE;
+            passed += (dial == 0) as u64;
+        }
+        landed += (dial == 0) as u64;
+    }
+
+    (landed, passed)
+}
+
+pub fn solve_p1_simulated(rotations: &str) -> u64 {
+    simulate(rotations).0
+}
+
+pub fn solve_p2_simulated(rotations: &str) -> u64 {
+    simulate(rotations).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators;
+
+    const EXAMPLE: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+    #[test]
+    fn example() {
+        assert_eq!(simulate(EXAMPLE), (3, 6));
+        assert_eq!(solve_p1(EXAMPLE), 3);
+        assert_eq!(solve_p2(EXAMPLE), 6);
+        assert_eq!(solve_p1_scan(EXAMPLE), 3);
+        assert_eq!(solve_p2_scan(EXAMPLE), 6);
+    }
+
+    #[test]
+    fn dial_example() {
+        let mut dial = Dial::default();
+        assert_eq!(dial.first_crossing(rotations(EXAMPLE)), Some(0));
+        assert_eq!(dial.position(), 82);
+
+        let mut dial = Dial::default();
+        assert_eq!(dial.time_on(rotations(EXAMPLE), 0), 3);
+        assert_eq!(
+            (dial.position(), dial.landings(), dial.crossings()),
+            (32, 3, 6)
+        );
+
+        let mut dial = Dial::default();
+        let steps: Vec<_> = dial.steps(rotations(EXAMPLE)).take(3).collect();
+        assert_eq!(steps[2].position, 0);
+        assert_eq!(steps.iter().map(|step| step.crossings).sum::<u64>(), 2);
+        assert_eq!(dial.position(), 0);
+    }
+
+    #[test]
+    fn small_dial() {
+        let mut dial = Dial::new(10, 5);
+        assert_eq!(dial.rotate(Rotation::Left(10)), 1);
+        assert_eq!(dial.position(), 5);
+        assert_eq!(dial.rotate(Rotation::Right(25)), 3);
+        assert_eq!(dial.position(), 0);
+        assert_eq!((dial.landings(), dial.crossings()), (1, 4));
+
+        // Starting on 0 and turning away from it doesnt count
+        assert_eq!(dial.rotate(Rotation::Left(3)), 0);
+        assert_eq!(dial.time_on([Rotation::Right(3), Rotation::Left(10)], 0), 2);
+    }
+
+    #[test]
+    fn prefix_matches_simulate() {
+        let input = generators::generate(2025, 1, 500, 3).unwrap();
+        let rotations: Vec<_> = rotations(&input).collect();
+        let expected = simulate(&input);
+        assert_eq!(prefix_walk(&input), expected);
+        for chunk_size in [1, 2, 3, 7, 64, rotations.len()] {
+            let chunks = scan::scan_chunks(&rotations, chunk_size, Shift::new, turn_chunk);
+            let total = chunks.iter().fold((0, 0), |(l, p), c| (l + c.0, p + c.1));
+            assert_eq!(total, expected, "chunks of {chunk_size}");
+        }
+    }
+
+    #[test]
+    fn combine_is_associative() {
+        let input = generators::generate(2025, 1, 500, 1).unwrap();
+        let rotations: Vec<_> = rotations(&input).collect();
+        let walks: Vec<_> = rotations.chunks(37).map(Walk::new).collect();
+        for abc in walks.windows(3) {
+            let [a, b, c] = abc else { unreachable!() };
+            assert_eq!(a.combine(b).combine(c), a.combine(&b.combine(c)));
+            assert_eq!(&Walk::empty().combine(a), a);
+            assert_eq!(&a.combine(&Walk::empty()), a);
+        }
+    }
+
+    #[test]
+    fn reduce_matches_simulate() {
+        let input = generators::generate(2025, 1, 500, 2).unwrap();
+        let rotations: Vec<_> = rotations(&input).collect();
+        let (landed, passed) = simulate(&input);
+        for chunk_size in [1, 2, 3, 7, 64, rotations.len()] {
+            let walk = scan::reduce_chunks(&rotations, chunk_size, Walk::new);
+            assert_eq!(
+                walk.landings[START as usize], landed,
+                "chunks of {chunk_size}"
+            );
+            assert_eq!(
+                walk.crossings[START as usize], passed,
+                "chunks of {chunk_size}"
+            );
+        }
+    }
+}
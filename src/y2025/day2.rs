@@ -0,0 +1,640 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, RangeInclusive, Rem, Sub};
+
+use crate::common::parse::{self, Separator};
+
+// Integer widths the id math runs in. u64 is enough for the puzzle, u128 takes bounds up to 38
+// digits and BigUint (bignum feature) anything. Sums go through checked math so an answer that
+// doesnt fit is an error instead of a wrapped number
+pub trait IdInt:
+    parse::Int
+    + Ord
+    + fmt::Display
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    // None when there is no biggest value
+    const MAX: Option<Self>;
+
+    fn from_u64(x: u64) -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    fn digit_count(&self) -> usize;
+}
+
+macro_rules! impl_id_int {
+    ($($t:ty),*) => {
+        $(impl IdInt for $t {
+            const MAX: Option<Self> = Some(<$t>::MAX);
+
+            fn from_u64(x: u64) -> Self {
+                x as Self
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *other)
+            }
+
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *other)
+            }
+
+            fn digit_count(&self) -> usize {
+                self.checked_ilog10().map_or(1, |log| log as usize + 1)
+            }
+        })*
+    };
+}
+
+impl_id_int!(u64, u128);
+
+#[cfg(feature = "bignum")]
+impl IdInt for num_bigint::BigUint {
+    const MAX: Option<Self> = None;
+
+    fn from_u64(x: u64) -> Self {
+        x.into()
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn digit_count(&self) -> usize {
+        self.to_string().len()
+    }
+}
+
+fn one<T: IdInt>() -> T {
+    T::from_u64(1)
+}
+
+// None if 10^n doesnt fit
+fn pow10<T: IdInt>(n: usize) -> Option<T> {
+    let ten = T::from_u64(10);
+    (0..n).try_fold(one(), |acc: T, _| acc.checked_mul(&ten))
+}
+
+fn div_ceil<T: IdInt>(a: &T, b: &T) -> T {
+    let rounded = a.clone() / b.clone();
+    if is_multiple_of(a, b) {
+        rounded
+    } else {
+        rounded + one()
+    }
+}
+
+fn is_multiple_of<T: IdInt>(a: &T, b: &T) -> bool {
+    a.clone() % b.clone() == T::ZERO
+}
+
+// One line of comma separated a-b ranges. Bounds that dont fit T are an error, pick a wider one
+pub fn parse<T: IdInt>(ids: &[u8]) -> Vec<RangeInclusive<T>> {
+    parse::ranges(ids, Separator::Comma)
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+// calculates the scalar needed to create a repeating pattern
+// For length 2 repeated 3 times it calculates 10101. None if it doesnt fit, then no id made with
+// it fits either
+fn get_repeating_multiplier<T: IdInt>(len: usize, rep_count: usize) -> Option<T> {
+    let mut mult = T::ZERO;
+    // This is the value we multiply by to shift left by one block
+    let block_shift = pow10::<T>(len)?;
+    let mut current_layer = one::<T>();
+
+    for i in 0..rep_count {
+        // Add the current layer like 1 then 100 then 10000
+        mult = mult.checked_add(&current_layer)?;
+
+        // The last layer doesnt need shifting, and might not fit if it was
+        if i + 1 < rep_count {
+            current_layer = current_layer.checked_mul(&block_shift)?;
+        }
+    }
+
+    Some(mult)
+}
+
+// The n digit seeds that repeated k times land in a range
+struct SeedWindow<T> {
+    n: usize,
+    k: usize,
+    multiplier: T,
+    first: T,
+    last: T,
+}
+
+// The seeds of length n that land in the range when repeated k times, if any do
+fn seed_window<T: IdInt>(range: &RangeInclusive<T>, n: usize, k: usize) -> Option<SeedWindow<T>> {
+    // Get the multiplier that turns a seed into a full id
+    let multiplier = get_repeating_multiplier::<T>(n, k)?;
+
+    // Define the bounds for an n digit seed, fits since n * k digits do
+    let n_min = pow10::<T>(n - 1)?;
+    let n_max = pow10::<T>(n)? - one();
+
+    // Translate the range into seed constraints
+    let seed_start = div_ceil(range.start(), &multiplier);
+    let seed_end = range.end().clone() / multiplier.clone();
+
+    // Constrain the seeds to the valid n digit window
+    let first = seed_start.max(n_min);
+    let last = seed_end.min(n_max);
+
+    (first <= last).then_some(SeedWindow {
+        n,
+        k,
+        multiplier,
+        first,
+        last,
+    })
+}
+
+// Every (seed length, repetition count) whose ids can land in the range. Ids are at most as long
+// as the range's end, so thats where the lengths stop
+fn seed_windows<T: IdInt>(range: &RangeInclusive<T>) -> impl Iterator<Item = SeedWindow<T>> + '_ {
+    let max_len = range.end().digit_count();
+
+    // Iterate through all possible seed lengths and repetition counts
+    (1..=max_len / 2)
+        .flat_map(move |n| (2..=max_len / n).map(move |k| (n, k)))
+        .filter_map(|(n, k)| seed_window(range, n, k))
+}
+
+// Sum of first..=last, None if it doesnt fit
+fn sum_between<T: IdInt>(first: &T, last: &T) -> Option<T> {
+    let count = last.clone() - first.clone() + one();
+    let ends = first.checked_add(last)?;
+    // One of them is even, halve that one first so the product cant overflow when the sum fits
+    let two = T::from_u64(2);
+    if is_multiple_of(&count, &two) {
+        (count / two).checked_mul(&ends)
+    } else {
+        count.checked_mul(&(ends / two))
+    }
+}
+
+// Part 1 ids are a seed written twice, like 1212
+fn sum_doubled<T: IdInt>(ranges: &[RangeInclusive<T>]) -> Option<T> {
+    let mut grand_total = T::ZERO;
+
+    for range in ranges {
+        // For part 1 the multiplier is just 10^n + 1, which generates numbers like 1010 or 1212
+        let max_len = range.end().digit_count();
+        for window in (1..=max_len / 2).filter_map(|n| seed_window(range, n, 2)) {
+            // Arithmetic progression sum formula, scaled up to the ids
+            let seed_sum = sum_between(&window.first, &window.last)?;
+            grand_total = grand_total.checked_add(&seed_sum.checked_mul(&window.multiplier)?)?;
+        }
+    }
+
+    Some(grand_total)
+}
+
+// Recursively calculates the sum of primitive seeds
+// Uses inclusion exclusion principle to subtract seeds formed by smaller periods
+fn sum_primitive_seeds<T: IdInt>(n: usize, min: T, max: T) -> Option<T> {
+    // Recursion base case: if the range is invalid return 0
+    if min > max {
+        return Some(T::ZERO);
+    }
+
+    // Calculate the raw sum of all numbers in this range
+    let mut total_sum = sum_between(&min, &max)?;
+
+    // Iterate over all divisors of n to find smaller repeating patterns
+    for d in 1..n {
+        if n.is_multiple_of(d) {
+            // Calculate how to scale a seed of length d to length n, fits since n digits do
+            let sub_multiplier = get_repeating_multiplier::<T>(d, n / d)?;
+
+            // Map the current bounds down to the smaller seed space
+            let sub_min = div_ceil(&min, &sub_multiplier);
+            let sub_max = max.clone() / sub_multiplier.clone();
+
+            // Recursively get the sum of primitive seeds for the divisor
+            let sub_sum = sum_primitive_seeds(d, sub_min, sub_max)?;
+
+            // Subtract these from the total because they look like
+            // length n but are actually repeated length d
+            total_sum = total_sum - sub_sum.checked_mul(&sub_multiplier)?;
+        }
+    }
+
+    Some(total_sum)
+}
+
+fn sum_repeating<T: IdInt>(ranges: &[RangeInclusive<T>]) -> Option<T> {
+    let mut total = T::ZERO;
+
+    for range in ranges {
+        for window in seed_windows(range) {
+            // Get sum of only the seeds that are length n
+            let prim_sum = sum_primitive_seeds(window.n, window.first, window.last)?;
+
+            // Scale the seed sum back up to the full id values
+            total = total.checked_add(&prim_sum.checked_mul(&window.multiplier)?)?;
+        }
+    }
+
+    Some(total)
+}
+
+// Same inclusion exclusion as sum_primitive_seeds, counting seeds instead of adding them up. Counts
+// are never more than the range is long so they always fit
+fn count_primitive_seeds<T: IdInt>(n: usize, min: T, max: T) -> T {
+    if min > max {
+        return T::ZERO;
+    }
+
+    let mut count = max.clone() - min.clone() + one();
+    for d in 1..n {
+        if n.is_multiple_of(d) {
+            let sub_multiplier = get_repeating_multiplier::<T>(d, n / d).unwrap();
+            count = count
+                - count_primitive_seeds(
+                    d,
+                    div_ceil(&min, &sub_multiplier),
+                    max.clone() / sub_multiplier,
+                );
+        }
+    }
+
+    count
+}
+
+// Range queries without listing the ids, each is a handful of inclusion exclusion sums so even
+// huge ranges are answered right away. This one is how many invalid ids are in the range
+pub fn count_in<T: IdInt>(range: &RangeInclusive<T>) -> T {
+    seed_windows(range).fold(T::ZERO, |count, window| {
+        count + count_primitive_seeds(window.n, window.first, window.last)
+    })
+}
+
+// None if the sum doesnt fit in T
+pub fn sum_in<T: IdInt>(range: &RangeInclusive<T>) -> Option<T> {
+    sum_repeating(std::slice::from_ref(range))
+}
+
+// How many invalid ids are <= x (rank)
+pub fn count_at_most<T: IdInt>(x: T) -> T {
+    count_in(&(T::ZERO..=x))
+}
+
+// The k-th smallest invalid id counting from 0, so select(0) is 11 (select). Binary search on
+// count_at_most, None past the last one that fits
+pub fn select<T: IdInt>(k: T) -> Option<T> {
+    // Upper bound first, the largest number of each length until one has enough below it
+    let mut hi = T::ZERO;
+    for len in 1.. {
+        let (bound, last) = match pow10::<T>(len) {
+            Some(p) => (p - one(), false),
+            None => (T::MAX?, true),
+        };
+        if count_at_most(bound.clone()) > k {
+            hi = bound;
+            break;
+        }
+        if last {
+            return None;
+        }
+    }
+
+    // Smallest x with more than k invalid ids at or below it
+    let mut lo = T::ZERO;
+    while lo < hi {
+        let mid = lo.clone() + (hi.clone() - lo.clone()) / T::from_u64(2);
+        if count_at_most(mid.clone()) > k {
+            hi = mid;
+        } else {
+            lo = mid + one();
+        }
+    }
+    Some(lo)
+}
+
+pub fn min_in<T: IdInt>(range: &RangeInclusive<T>) -> Option<T> {
+    if count_in(range) == T::ZERO {
+        return None;
+    }
+    // Rank of the first one inside is how many come before the range
+    let before = if *range.start() == T::ZERO {
+        T::ZERO
+    } else {
+        count_at_most(range.start().clone() - one())
+    };
+    select(before)
+}
+
+pub fn max_in<T: IdInt>(range: &RangeInclusive<T>) -> Option<T> {
+    if count_in(range) == T::ZERO {
+        return None;
+    }
+    select(count_at_most(range.end().clone()) - one())
+}
+
+pub fn solve_p1(ids: &str) -> u64 {
+    solve_p1_bytes(ids.as_bytes())
+}
+
+pub fn solve_p1_bytes(ids: &[u8]) -> u64 {
+    solve_p1_in::<u64>(ids)
+}
+
+pub fn solve_p2(ids: &str) -> u64 {
+    solve_p2_bytes(ids.as_bytes())
+}
+
+pub fn solve_p2_bytes(ids: &[u8]) -> u64 {
+    solve_p2_in::<u64>(ids)
+}
+
+// Both parts in any width, for inputs with bounds (or answers) too big for u64
+pub fn solve_p1_in<T: IdInt>(ids: &[u8]) -> T {
+    sum_doubled(&parse::<T>(ids)).expect("Sum doesnt fit, try a wider integer")
+}
+
+pub fn solve_p2_in<T: IdInt>(ids: &[u8]) -> T {
+    sum_repeating(&parse::<T>(ids)).expect("Sum doesnt fit, try a wider integer")
+}
+
+// Checks every id in every range by looking at its digits, fine for small inputs and a way to
+// cross-check the seed math above
+fn brute_force(ids: &str, repeats: fn(&[u8]) -> bool) -> u64 {
+    parse::<u64>(ids.as_bytes())
+        .into_iter()
+        .flatten()
+        .filter(|id| repeats(id.to_string().as_bytes()))
+        .sum()
+}
+
+// Made of some block repeated `k` times
+fn repeats_times(digits: &[u8], k: usize) -> bool {
+    let len = digits.len();
+    len.is_multiple_of(k)
+        && digits
+            .chunks(len / k)
+            .all(|block| block == &digits[..len / k])
+}
+
+pub fn solve_p1_brute(ids: &str) -> u64 {
+    brute_force(ids, |digits| repeats_times(digits, 2))
+}
+
+pub fn solve_p2_brute(ids: &str) -> u64 {
+    brute_force(ids, |digits| {
+        (2..=digits.len()).any(|k| repeats_times(digits, k))
+    })
+}
+
+// Same answer as solve_p2 from listing every invalid id, slower but checks the iterator below
+pub fn solve_p2_listed(ids: &str) -> u64 {
+    parse::<u64>(ids.as_bytes())
+        .iter()
+        .flat_map(invalid_ids)
+        .map(|invalid| invalid.id)
+        .sum()
+}
+
+// An invalid id along with the seed that makes it, the seed is always the shortest block so each
+// id shows up once (222222 is 2 six times, not 22 three times)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InvalidId<T = u64> {
+    pub id: T,
+    pub seed: T,
+    pub repetitions: usize,
+}
+
+// Seed is made of a smaller block repeated, so its ids are already counted under that block
+fn is_primitive<T: IdInt>(seed: &T, n: usize) -> bool {
+    (1..n).filter(|&d| n.is_multiple_of(d)).all(|d| {
+        let multiplier = get_repeating_multiplier::<T>(d, n / d).unwrap();
+        !is_multiple_of(seed, &multiplier)
+    })
+}
+
+// The ids made by n digit seeds repeated k times, ascending
+struct SeedRun<T> {
+    n: usize,
+    k: usize,
+    multiplier: T,
+    seed: T,
+    last: T,
+}
+
+impl<T: IdInt> SeedRun<T> {
+    // Skips seeds that arent primitive, returns the id of the next one
+    fn peek(&mut self) -> Option<T> {
+        while self.seed <= self.last && !is_primitive(&self.seed, self.n) {
+            self.seed = self.seed.clone() + one();
+        }
+        (self.seed <= self.last).then(|| self.seed.clone() * self.multiplier.clone())
+    }
+}
+
+// Every invalid id in a range in ascending order, see invalid_ids
+pub struct InvalidIds<T = u64> {
+    range: RangeInclusive<T>,
+    len: usize,
+    runs: Vec<SeedRun<T>>,
+}
+
+// Ids of the same length are merged from one run per way of splitting that length into k >= 2
+// equal blocks, longer ids are always bigger so lengths are just done one after the other
+pub fn invalid_ids<T: IdInt>(range: &RangeInclusive<T>) -> InvalidIds<T> {
+    let mut ids = InvalidIds {
+        range: range.clone(),
+        len: range.start().digit_count(),
+        runs: Vec::new(),
+    };
+    ids.start_len();
+    ids
+}
+
+impl<T: IdInt> InvalidIds<T> {
+    // Sets up the runs for ids that are `len` digits long, same windows as sum_repeating
+    fn start_len(&mut self) {
+        let len = self.len;
+        self.runs = seed_windows(&self.range)
+            .filter(|window| window.n * window.k == len)
+            .map(|window| SeedRun {
+                n: window.n,
+                k: window.k,
+                multiplier: window.multiplier,
+                seed: window.first,
+                last: window.last,
+            })
+            .collect();
+    }
+}
+
+impl<T: IdInt> Iterator for InvalidIds<T> {
+    type Item = InvalidId<T>;
+
+    fn next(&mut self) -> Option<InvalidId<T>> {
+        loop {
+            // Each id has a single primitive seed, so runs never give the same id twice
+            let next = self
+                .runs
+                .iter_mut()
+                .filter_map(|run| Some((run.peek()?, run)))
+                .min_by(|(a, _), (b, _)| a.cmp(b));
+
+            if let Some((id, run)) = next {
+                let found = InvalidId {
+                    id,
+                    seed: run.seed.clone(),
+                    repetitions: run.k,
+                };
+                run.seed = run.seed.clone() + one();
+                return Some(found);
+            }
+
+            if self.len >= self.range.end().digit_count() {
+                return None;
+            }
+            self.len += 1;
+            self.start_len();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: u64 = 2500;
+
+    fn is_invalid(id: impl ToString) -> bool {
+        let digits = id.to_string();
+        (2..=digits.len()).any(|k| repeats_times(digits.as_bytes(), k))
+    }
+
+    // Every invalid id up to LIMIT, and bounds to build ranges from: each of them, its neighbours
+    // and a few that arent near one
+    fn brute() -> (Vec<u64>, Vec<u64>) {
+        let invalid: Vec<u64> = (0..=LIMIT).filter(|&id| is_invalid(id)).collect();
+        let mut bounds = vec![0, 1, 5, 10, 50, 100, 500, 1000, 2000, LIMIT];
+        for &id in &invalid {
+            bounds.extend([id - 1, id, id + 1]);
+        }
+        bounds.sort_unstable();
+        bounds.dedup();
+        (invalid, bounds)
+    }
+
+    #[test]
+    fn rank_and_select() {
+        let (invalid, _) = brute();
+        for x in 0..=LIMIT {
+            let below = invalid.iter().filter(|&&id| id <= x).count() as u64;
+            assert_eq!(count_at_most(x), below, "count_at_most({x})");
+        }
+        for (k, &id) in invalid.iter().enumerate() {
+            assert_eq!(select(k as u64), Some(id), "select({k})");
+        }
+    }
+
+    #[test]
+    fn select_past_the_end() {
+        let total = count_at_most(u64::MAX);
+        let last = select(total - 1).unwrap();
+        assert!(is_invalid(last));
+        assert_eq!(count_at_most(last - 1), total - 1);
+        assert_eq!(select(total), None);
+        assert_eq!(select(u64::MAX), None);
+    }
+
+    // Ascending, and every id rebuilt from its seed and repetition count
+    #[test]
+    fn listed_ids() {
+        let (invalid, bounds) = brute();
+        for &lo in &bounds {
+            for &hi in &bounds {
+                let range = lo..=hi;
+                let listed: Vec<_> = invalid_ids(&range).collect();
+                let ids: Vec<u64> = listed.iter().map(|invalid| invalid.id).collect();
+                let expected: Vec<u64> = invalid
+                    .iter()
+                    .copied()
+                    .filter(|id| range.contains(id))
+                    .collect();
+                assert_eq!(ids, expected, "invalid_ids({range:?})");
+                assert!(
+                    ids.windows(2).all(|w| w[0] < w[1]),
+                    "{range:?} not ascending"
+                );
+
+                for invalid in &listed {
+                    assert!(invalid.repetitions >= 2);
+                    // The shortest block, so each id is listed once
+                    assert!(!is_invalid(invalid.seed));
+                    let rebuilt = invalid.seed.to_string().repeat(invalid.repetitions);
+                    assert_eq!(rebuilt, invalid.id.to_string());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_queries() {
+        let (invalid, bounds) = brute();
+        for &lo in &bounds {
+            for &hi in &bounds {
+                // lo > hi are the empty ranges
+                let range = lo..=hi;
+                let inside: Vec<u64> = invalid
+                    .iter()
+                    .copied()
+                    .filter(|id| range.contains(id))
+                    .collect();
+                assert_eq!(count_in(&range), inside.len() as u64, "count_in({range:?})");
+                assert_eq!(
+                    sum_in(&range),
+                    Some(inside.iter().sum()),
+                    "sum_in({range:?})"
+                );
+                assert_eq!(min_in(&range), inside.first().copied(), "min_in({range:?})");
+                assert_eq!(max_in(&range), inside.last().copied(), "max_in({range:?})");
+            }
+        }
+    }
+
+    // Bounds past u64::MAX, up to the 38 digits u128 takes. The window around a doubled 19 digit
+    // seed is small enough to check every id in it
+    #[test]
+    fn u128_past_u64() {
+        let seed: u128 = 1234567890123456789;
+        let doubled = seed * 10u128.pow(19) + seed;
+        let range = (doubled - 2000)..=(doubled + 2000);
+        assert!(*range.start() > u64::MAX as u128);
+
+        let inside: Vec<u128> = range.clone().filter(|&id| is_invalid(id)).collect();
+        assert!(inside.contains(&doubled));
+        assert_eq!(count_in(&range), inside.len() as u128);
+        assert_eq!(sum_in(&range), Some(inside.iter().sum()));
+        assert_eq!(min_in(&range), inside.first().copied());
+        assert_eq!(max_in(&range), inside.last().copied());
+
+        let ids = format!("{}-{}", range.start(), range.end());
+        assert_eq!(solve_p1_in::<u128>(ids.as_bytes()), doubled);
+        assert_eq!(solve_p2_in::<u128>(ids.as_bytes()), inside.iter().sum());
+
+        // The largest 38 digit number is the only invalid id near it
+        let nines = 10u128.pow(38) - 1;
+        let ids = format!("{}-{}", nines - 9, nines);
+        assert_eq!(count_in(&((nines - 9)..=nines)), 1);
+        assert_eq!(solve_p1_in::<u128>(ids.as_bytes()), nines);
+        assert_eq!(solve_p2_in::<u128>(ids.as_bytes()), nines);
+    }
+}
@@ -0,0 +1,252 @@
+use std::cmp::max;
+use std::ops::ControlFlow;
+
+use crate::common::parse;
+use crate::streaming::LineFold;
+
+// Trait defines shared behavior so we can swap implementations
+// Allows us to run different memory strategies without code duplication
+pub(crate) trait InvertedIndex {
+    fn build(&mut self, bank: &[u8]);
+    // Stateful search that remembers its place
+    // Finds first valid index for digit within range
+    fn find_next(&mut self, digit: usize, min_idx: usize, max_idx: usize) -> Option<usize>;
+}
+
+// Naive approach using a vector of vectors
+// Simple to write but hurts cache locality due to pointer chasing
+#[derive(Default)]
+pub(crate) struct NaiveIndex {
+    data: [Vec<usize>; 10],
+    cursors: [usize; 10], // Tracks current position in each vec
+}
+
+impl InvertedIndex for NaiveIndex {
+    fn build(&mut self, bank: &[u8]) {
+        // Reuse capacity and reset state
+        for i in 0..10 {
+            self.data[i].clear();
+            self.cursors[i] = 0;
+        }
+
+        // Standard pass pushing indices into their digit buckets
+        bank.iter().enumerate().for_each(|(i, &x)| {
+            let x = (x - b'0') as usize;
+            self.data[x].push(i);
+        });
+    }
+
+    fn find_next(&mut self, digit: usize, min_idx: usize, max_idx: usize) -> Option<usize> {
+        let vec = &self.data[digit];
+        let mut ptr = self.cursors[digit];
+
+        // Scan forward from last known position
+        while ptr < vec.len() {
+            let val = vec[ptr];
+            if val >= min_idx {
+                // Found candidate start so save position
+                self.cursors[digit] = ptr;
+
+                if val <= max_idx {
+                    return Some(val);
+                } else {
+                    // Vector is sorted so no future values will work
+                    return None;
+                }
+            }
+            ptr += 1;
+        }
+
+        // Reached end of list
+        self.cursors[digit] = ptr;
+        None
+    }
+}
+
+// Optimized approach using a single flat buffer
+// Better cache locality and 4x denser memory using u16
+#[derive(Default)]
+pub(crate) struct FastIndex {
+    buffer: Vec<u16>,
+    starts: [usize; 11], // 11th slot acts as sentinel
+    cursors: [usize; 10],
+}
+
+impl InvertedIndex for FastIndex {
+    fn build(&mut self, bytes: &[u8]) {
+        let len = bytes.len();
+
+        // First pass counts occurrences to determine partition sizes
+        let mut counts = [0usize; 10];
+        for &b in bytes {
+            counts[(b - b'0') as usize] += 1;
+        }
+
+        // Compute prefix sums to find where each digit partition starts
+        let mut current_offset = 0;
+        for (i, count) in counts.iter().enumerate() {
+            self.starts[i] = current_offset;
+            self.cursors[i] = current_offset; // Reset cursors to start
+            current_offset += count;
+        }
+        self.starts[10] = current_offset; // Sentinel
+
+        // Reset buffer length without dropping capacity
+        self.buffer.clear();
+        self.buffer.resize(len, 0);
+
+        // Second pass fills the flat buffer using a mutable cursor
+        // This scatters indices into their precalculated slots
+        let mut write_pos = self.starts;
+        for (i, &b) in bytes.iter().enumerate() {
+            let digit = (b - b'0') as usize;
+            let pos = write_pos[digit];
+            self.buffer[pos] = i as u16;
+            write_pos[digit] += 1;
+        }
+    }
+
+    fn find_next(&mut self, digit: usize, min_idx: usize, max_idx: usize) -> Option<usize> {
+        // Use sentinel to avoid storing separate lengths
+        let limit = self.starts[digit + 1];
+        let mut ptr = self.cursors[digit];
+
+        while ptr < limit {
+            // Raw buffer access is cache friendly
+            let val = self.buffer[ptr] as usize;
+
+            if val >= min_idx {
+                // Found candidate start valid for start condition
+                // Update cursor so we never scan previous indices again
+                self.cursors[digit] = ptr;
+
+                if val <= max_idx {
+                    return Some(val);
+                } else {
+                    return None;
+                }
+            }
+            ptr += 1;
+        }
+
+        self.cursors[digit] = ptr;
+        None
+    }
+}
+
+// Largest 12 digit joltage of one bank, the indexer is passed in so its buffers get reused
+fn bank_joltage<T: InvertedIndex>(indexer: &mut T, bank: &[u8]) -> u64 {
+    indexer.build(bank);
+
+    let mut total = 0;
+    let mut cur_pos = 0;
+    let len = bank.len();
+
+    for digits_left in (1..=12).rev() {
+        let max_valid = len - digits_left;
+
+        // Greedy approach trying largest digits first
+        for checking_digit in (0..=9).rev() {
+            // Indexer handles the search logic and state
+            if let Some(idx) = indexer.find_next(checking_digit, cur_pos, max_valid) {
+                total = total * 10 + checking_digit as u64;
+                crate::trace!(2, "day3.digit", digit = checking_digit, index = idx);
+                cur_pos = idx + 1;
+                break;
+            }
+        }
+    }
+    crate::trace!(1, "day3.bank", joltage = total);
+    total
+}
+
+// Generic solver that accepts any type implementing our trait
+// Monomorphization generates two distinct efficient functions
+fn solve_p2_generic<T: InvertedIndex + Default>(banks: &[u8]) -> u64 {
+    let mut indexer = T::default();
+
+    parse::lines(banks)
+        .map(|bank| bank_joltage(&mut indexer, bank))
+        .sum()
+}
+
+// First part only needs to find a pair of digits, rather than 12
+fn pair_joltage(bank: &[u8]) -> usize {
+    let pair = bank
+        .iter()
+        .fold((0, 0), |(max_pair, max_digit), &x| {
+            let x = (x - b'0') as usize;
+            let new_pair = max(max_digit * 10 + x, max_pair);
+            let new_x = max(x, max_digit);
+            (new_pair, new_x)
+        })
+        .0;
+    crate::trace!(1, "day3.bank", joltage = pair);
+    pair
+}
+
+pub fn solve_p1(banks: &str) -> usize {
+    solve_p1_bytes(banks.as_bytes())
+}
+
+// Banks are plain ASCII digits, so everything works on bytes and huge inputs can skip UTF-8
+// validation (see input::read_bytes)
+pub fn solve_p1_bytes(banks: &[u8]) -> usize {
+    parse::lines(banks).map(pair_joltage).sum()
+}
+
+// Each wrapper picks which compiled version of the generic solver runs
+pub fn solve_p2(banks: &str) -> u64 {
+    solve_p2_bytes(banks.as_bytes())
+}
+
+pub fn solve_p2_fast(banks: &str) -> u64 {
+    solve_p2_fast_bytes(banks.as_bytes())
+}
+
+pub fn solve_p2_bytes(banks: &[u8]) -> u64 {
+    solve_p2_generic::<NaiveIndex>(banks)
+}
+
+pub fn solve_p2_fast_bytes(banks: &[u8]) -> u64 {
+    solve_p2_generic::<FastIndex>(banks)
+}
+
+// Every bank is independent, so both parts can also be solved one line at a time (see streaming)
+#[derive(Default)]
+pub(crate) struct StreamP1(usize);
+
+impl LineFold for StreamP1 {
+    type Output = usize;
+
+    fn line(&mut self, bank: &[u8]) -> ControlFlow<()> {
+        self.0 += pair_joltage(bank);
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct StreamP2<T> {
+    indexer: T,
+    total: u64,
+}
+
+impl<T: InvertedIndex + Default> LineFold for StreamP2<T> {
+    type Output = u64;
+
+    fn line(&mut self, bank: &[u8]) -> ControlFlow<()> {
+        // Trailing blank lines arent cut off when streaming
+        if !bank.is_empty() {
+            self.total += bank_joltage(&mut self.indexer, bank);
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self) -> u64 {
+        self.total
+    }
+}
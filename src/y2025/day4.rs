@@ -0,0 +1,220 @@
+use crate::common::grid::PaddedGrid;
+use crate::vis::{Frame, Recorder};
+
+fn is_at(char: Option<&u8>) -> usize {
+    match char {
+        Some(&b'@') => 1,
+        _ => 0,
+    }
+}
+
+// naive-solution. loop through char matrix and check 8 surrounding positions
+// using index math
+pub fn solve_p1(diagram: &str) -> Option<usize> {
+    let diagram: Vec<Vec<u8>> = diagram.lines().map(|s| s.as_bytes().to_vec()).collect();
+
+    let mut total = 0;
+    for i in 0..diagram.len() {
+        let line = diagram.get(i)?;
+        for j in 0..line.len() {
+            let char = line.get(j)?;
+
+            // Skip if not '@'
+            if is_at(Some(char)) == 0 {
+                continue;
+            }
+
+            let mut neighbor_count = 0;
+            // Top Row
+            neighbor_count += is_at(
+                diagram
+                    .get(i.wrapping_sub(1))
+                    .and_then(|l| l.get(j.wrapping_sub(1))),
+            ); // Top-Left
+            neighbor_count += is_at(diagram.get(i.wrapping_sub(1)).and_then(|l| l.get(j))); // Top-Center
+            neighbor_count += is_at(diagram.get(i.wrapping_sub(1)).and_then(|l| l.get(j + 1))); // Top-Right
+
+            // Middle Row
+            neighbor_count += is_at(diagram.get(i).and_then(|l| l.get(j.wrapping_sub(1)))); // Left
+            neighbor_count += is_at(diagram.get(i).and_then(|l| l.get(j + 1))); // Right
+
+            // Bottom Row
+            neighbor_count += is_at(diagram.get(i + 1).and_then(|l| l.get(j.wrapping_sub(1)))); // Bottom-Left
+            neighbor_count += is_at(diagram.get(i + 1).and_then(|l| l.get(j))); // Bottom-Center
+            neighbor_count += is_at(diagram.get(i + 1).and_then(|l| l.get(j + 1))); // Bottom-Right
+
+            if neighbor_count < 4 {
+                total += 1
+            }
+        }
+    }
+
+    Some(total)
+}
+
+pub fn solve_p1_flattened(diagram: &str) -> usize {
+    // Flattened diagram. Padding avoids boundary checks (thus avoiding branches)
+    let grid = PaddedGrid::parse(diagram, b'.');
+    let (width, height, stride) = (grid.width, grid.height, grid.stride);
+    let offset = grid.neighbor_offsets();
+
+    // Idiomatic rust solution using iterators
+    // Ends up being not so fast. To take full advantaged of flattened array (which provides cache
+    // locality) we need manually run loops and unroll some of them too(plus we unrolled in naive
+    // already).
+    // (1..=height)
+    //     .flat_map(|y| {
+    //         let row_start = y * stride + 1;
+    //         let row_end = row_start + width;
+    //         row_start..row_end
+    //     })
+    //     .filter(|&i| grid[i] == b'@')
+    //     .filter(|&i| {
+    //         let neighbor_count = offset
+    //             .iter()
+    //             // We can add offset without worrying about overflow because of padding
+    //             .filter(|&&offset| grid[(i as isize + offset) as usize] == b'@')
+    //             .count();
+    //
+    //         neighbor_count < 4
+    //     })
+    //     .count()
+
+    // Avoid rust abstractions, handle flattened array calculation iteration
+    // & checks manually
+    let mut count = 0;
+
+    for y in 1..=height {
+        let row_start = y * stride + 1;
+        for i in row_start..(row_start + width) {
+            if grid[i] != b'@' {
+                continue;
+            }
+
+            // manually unroll loop (done by filter before) like the naive approach
+            let neighbors = (grid[(i as isize + offset[0]) as usize] == b'@') as usize
+                + (grid[(i as isize + offset[1]) as usize] == b'@') as usize
+                + (grid[(i as isize + offset[2]) as usize] == b'@') as usize
+                + (grid[(i as isize + offset[3]) as usize] == b'@') as usize
+                + (grid[(i as isize + offset[4]) as usize] == b'@') as usize
+                + (grid[(i as isize + offset[5]) as usize] == b'@') as usize
+                + (grid[(i as isize + offset[6]) as usize] == b'@') as usize
+                + (grid[(i as isize + offset[7]) as usize] == b'@') as usize;
+
+            if neighbors < 4 {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+pub fn solve_p2(diagram: &str) -> usize {
+    // Flattened diagram. Padding avoids boundary checks (thus avoiding branches)
+    let mut grid = PaddedGrid::parse(diagram, b'.');
+    let (width, height, stride) = (grid.width, grid.height, grid.stride);
+    let offsets = grid.neighbor_offsets();
+
+    // Setup queue to check. We can treat this as a flood fill problem because paper rolls that are
+    // neighbors of the initial count are the only ones that need rechecking.
+    let mut queue: Vec<usize> = Vec::with_capacity(width * height / 4);
+    let mut total_removed = 0;
+
+    // Perform first check to fill queue
+    for y in 1..=height {
+        let row_start = y * stride + 1;
+        for i in row_start..(row_start + width) {
+            if grid[i] != b'@' {
+                continue;
+            }
+
+            // manually unroll loop (done by filter before) like the naive approach
+            let neighbors = (grid[(i as isize + offsets[0]) as usize] == b'@') as usize
+                + (grid[(i as isize + offsets[1]) as usize] == b'@') as usize
+                + (grid[(i as isize + offsets[2]) as usize] == b'@') as usize
+                + (grid[(i as isize + offsets[3]) as usize] == b'@') as usize
+                + (grid[(i as isize + offsets[4]) as usize] == b'@') as usize
+                + (grid[(i as isize + offsets[5]) as usize] == b'@') as usize
+                + (grid[(i as isize + offsets[6]) as usize] == b'@') as usize
+                + (grid[(i as isize + offsets[7]) as usize] == b'@') as usize;
+
+            if neighbors < 4 {
+                grid[i] = b'.';
+                queue.push(i);
+                total_removed += 1;
+            }
+        }
+    }
+
+    // Now we only need to perform operation on items in queue until its empty
+    // rather than rechecking the entire diagram each time
+    crate::trace!(
+        1,
+        "day4.first_pass",
+        removed = total_removed,
+        queue = queue.len()
+    );
+
+    while let Some(idx) = queue.pop() {
+        crate::trace!(2, "day4.queue", len = queue.len(), removed = total_removed);
+        for &offset in &offsets {
+            let neighbor_idx = (idx as isize + offset) as usize;
+
+            if grid[neighbor_idx] == b'@' {
+                let neighbors = (grid[(neighbor_idx as isize + offsets[0]) as usize] == b'@')
+                    as usize
+                    + (grid[(neighbor_idx as isize + offsets[1]) as usize] == b'@') as usize
+                    + (grid[(neighbor_idx as isize + offsets[2]) as usize] == b'@') as usize
+                    + (grid[(neighbor_idx as isize + offsets[3]) as usize] == b'@') as usize
+                    + (grid[(neighbor_idx as isize + offsets[4]) as usize] == b'@') as usize
+                    + (grid[(neighbor_idx as isize + offsets[5]) as usize] == b'@') as usize
+                    + (grid[(neighbor_idx as isize + offsets[6]) as usize] == b'@') as usize
+                    + (grid[(neighbor_idx as isize + offsets[7]) as usize] == b'@') as usize;
+
+                if neighbors < 4 {
+                    grid[neighbor_idx] = b'.';
+                    queue.push(neighbor_idx);
+                    total_removed += 1;
+                }
+            }
+        }
+    }
+
+    crate::trace!(1, "day4.done", removed = total_removed);
+    total_removed
+}
+
+// Replays part 2 as synchronous waves for the visualizer: every wave removes all rolls that are
+// accessible at the start of it. solve_p2 removes one roll at a time from its queue so its order
+// isnt as nice to watch, but both end with the same rolls removed
+pub fn visualize_p2(diagram: &str, recorder: &mut Recorder) {
+    let mut grid = PaddedGrid::parse(diagram, b'.');
+    let offsets = grid.neighbor_offsets();
+
+    loop {
+        let wave: Vec<usize> = grid
+            .rows()
+            .flatten()
+            .filter(|&i| grid[i] == b'@')
+            .filter(|&i| {
+                offsets
+                    .iter()
+                    .filter(|&&o| grid[(i as isize + o) as usize] == b'@')
+                    .count()
+                    < 4
+            })
+            .collect();
+
+        // Show the wave highlighted before it disappears
+        let highlight = wave.iter().map(|&i| grid.unpadded_index(i)).collect();
+        recorder
+            .push(Frame::new(grid.width, grid.height, grid.unpadded()).with_highlight(highlight));
+
+        if wave.is_empty() {
+            break;
+        }
+        for &i in &wave {
+            grid[i] = b'.';
+        }
+    }
+}
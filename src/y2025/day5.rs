@@ -0,0 +1,171 @@
+use std::ops::ControlFlow;
+use std::sync::Arc;
+use std::thread;
+
+use crate::common::parse::{self, Separator};
+use crate::streaming::LineFold;
+
+type ChristmasRange = std::ops::RangeInclusive<u64>;
+
+// The ranges and the ids are separated by a blank line
+fn split_sections(elf_db: &[u8]) -> (&[u8], &[u8]) {
+    let blank = elf_db
+        .windows(2)
+        .position(|w| w == b"\n\n")
+        .expect("no blank line between ranges and ids");
+    (&elf_db[..blank], &elf_db[blank + 2..])
+}
+
+fn create_merged_sorted_ranges(elf_db: &[u8]) -> Vec<ChristmasRange> {
+    // One a-b range per line
+    let ranges: Vec<ChristmasRange> = parse::ranges(elf_db, Separator::Whitespace)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    merge_ranges(ranges)
+}
+
+fn merge_ranges(mut ranges: Vec<ChristmasRange>) -> Vec<ChristmasRange> {
+    // Sort vec by range start for easier merge & later logic
+    ranges.sort_by(|a, b| a.start().cmp(b.start()));
+
+    // Merge sorted ranges by folding. Accumulator keeps our final result so either merge and add
+    // or just add to acc
+    let mut iter = ranges.into_iter();
+    let first = iter.next().unwrap();
+
+    iter.fold(vec![first], |mut acc, r| {
+        let last = acc.last_mut().unwrap();
+
+        if *r.start() <= *last.end() + 1 {
+            let new_end = std::cmp::max(*last.end(), *r.end());
+            *last = *last.start()..=new_end;
+        } else {
+            acc.push(r);
+        }
+        acc
+    })
+}
+
+// Binary search over the merged (so sorted and disjoint) ranges
+fn is_fresh(ranges: &[ChristmasRange], id: u64) -> bool {
+    ranges
+        .binary_search_by(|range| {
+            if range.contains(&id) {
+                std::cmp::Ordering::Equal
+            } else if *range.start() > id {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Less
+            }
+        })
+        .is_ok()
+}
+
+pub fn solve_p1(elf_db: &str) -> u64 {
+    solve_p1_bytes(elf_db.as_bytes())
+}
+
+// The database is all ASCII, so huge inputs can be solved straight off the (memory mapped) bytes
+pub fn solve_p1_bytes(elf_db: &[u8]) -> u64 {
+    let (ranges, ids) = split_sections(elf_db);
+    let ranges = Arc::new(create_merged_sorted_ranges(ranges));
+    let ids: Arc<Vec<u64>> = Arc::new(
+        parse::list(ids, Separator::Whitespace)
+            .collect::<Result<_, _>>()
+            .unwrap(),
+    );
+
+    // Calculate ids (in chunk) per thread
+    let num_threads = thread::available_parallelism().unwrap().get();
+    let chunk_size = ids.len().div_ceil(num_threads);
+
+    let handles: Vec<_> = (0..num_threads)
+        .map(|i| {
+            let ranges = ranges.clone();
+            let ids = ids.clone();
+
+            thread::spawn(move || {
+                let start = i * chunk_size;
+                let end = std::cmp::min((i + 1) * chunk_size, ids.len());
+                if start >= ids.len() {
+                    return 0;
+                }
+                let chunk = &ids[start..end];
+
+                chunk.iter().filter(|&&id| is_fresh(&ranges, id)).count()
+            })
+        })
+        .collect();
+
+    handles.into_iter().map(|h| h.join().unwrap() as u64).sum()
+}
+
+pub fn solve_p2(elf_db: &str) -> u64 {
+    solve_p2_bytes(elf_db.as_bytes())
+}
+
+pub fn solve_p2_bytes(elf_db: &[u8]) -> u64 {
+    // Ironically part 2 is far easier, just have to count the total amount of possible fresh
+    // ingredients (sum each range)
+    let (ranges, _) = split_sections(elf_db);
+    let ranges = create_merged_sorted_ranges(ranges);
+
+    ranges.into_iter().map(|r| *r.end() - *r.start() + 1).sum()
+}
+
+// Streaming: the ranges are collected and merged at the blank line, after that every id is checked
+// as it comes in so there can be any number of them
+#[derive(Default)]
+pub(crate) struct StreamP1 {
+    ranges: Vec<ChristmasRange>,
+    checking: bool,
+    fresh: u64,
+}
+
+impl LineFold for StreamP1 {
+    type Output = u64;
+
+    fn line(&mut self, line: &[u8]) -> ControlFlow<()> {
+        if self.checking {
+            if !line.is_empty() {
+                self.fresh += is_fresh(&self.ranges, parse::unsigned(line).unwrap()) as u64;
+            }
+        } else if line.is_empty() {
+            self.ranges = merge_ranges(std::mem::take(&mut self.ranges));
+            self.checking = true;
+        } else {
+            self.ranges.push(parse::range(line).unwrap());
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self) -> u64 {
+        self.fresh
+    }
+}
+
+// Part 2 only needs the ranges, so it stops reading at the blank line
+#[derive(Default)]
+pub(crate) struct StreamP2 {
+    ranges: Vec<ChristmasRange>,
+}
+
+impl LineFold for StreamP2 {
+    type Output = u64;
+
+    fn line(&mut self, line: &[u8]) -> ControlFlow<()> {
+        if line.is_empty() {
+            return ControlFlow::Break(());
+        }
+        self.ranges.push(parse::range(line).unwrap());
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self) -> u64 {
+        merge_ranges(self.ranges)
+            .into_iter()
+            .map(|r| *r.end() - *r.start() + 1)
+            .sum()
+    }
+}
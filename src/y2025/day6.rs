@@ -0,0 +1,129 @@
+use crate::common::parse::Cursor;
+
+pub fn solve_p1(homework: &str) -> u64 {
+    let homework = homework.trim_end().as_bytes();
+
+    // Ops are on the last line, every line above it has one number per column
+    let ops_start = homework
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1);
+
+    // Acumulators store results for each column & the op
+    let mut accumulators: Vec<(u64, u8)> = homework[ops_start..]
+        .iter()
+        .filter(|b| matches!(b, b'+' | b'*'))
+        .map(|&op| (if op == b'+' { 0 } else { 1 }, op))
+        .collect();
+
+    let mut numbers = Cursor::new(&homework[..ops_start]);
+    let mut col_idx = 0;
+
+    loop {
+        numbers.skip_spaces();
+        if numbers.is_empty() {
+            break;
+        }
+        if numbers.eat(b'\n') {
+            col_idx = 0;
+            continue;
+        }
+
+        apply_op(&mut accumulators, col_idx, numbers.unsigned().unwrap());
+        col_idx += 1;
+    }
+
+    accumulators.iter().map(|(n, _)| *n).sum()
+}
+
+#[inline(always)]
+fn apply_op(accs: &mut [(u64, u8)], idx: usize, num: u64) {
+    if let Some((acc, op)) = accs.get_mut(idx) {
+        match op {
+            b'+' => *acc += num,
+            b'*' => *acc *= num,
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub fn solve_p2(homework: &str) -> u64 {
+    let rows: Vec<&[u8]> = homework.trim().lines().map(|l| l.as_bytes()).collect();
+
+    let height = rows.len();
+    let num_rows = height - 1;
+    let op_row_idx = height - 1;
+
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+
+    // State for current block
+    let mut block_numbers = Vec::new();
+    let mut block_op = None;
+    let mut grand_total = 0;
+
+    for x in (0..width).rev() {
+        let mut col_is_empty = true;
+        let mut curr_col_val = 0;
+        let mut has_digit = false;
+
+        for row in &rows[..num_rows] {
+            // If line is short treat as space (for numbers with digits < height)
+            let byte = row.get(x).copied().unwrap_or(b' ');
+
+            if byte.is_ascii_digit() {
+                col_is_empty = false;
+                has_digit = true;
+                curr_col_val = curr_col_val * 10 + (byte - b'0') as u64;
+            }
+        }
+
+        let op_byte = rows[op_row_idx].get(x).copied().unwrap_or(b' ');
+        if matches!(op_byte, b'+' | b'*') {
+            col_is_empty = false;
+            block_op = Some(op_byte);
+        }
+
+        if col_is_empty {
+            if !block_numbers.is_empty() {
+                grand_total += resolve_block(&block_numbers, block_op);
+                block_numbers.clear();
+                block_op = None;
+            }
+        } else if has_digit {
+            block_numbers.push(curr_col_val);
+        }
+    }
+
+    if !block_numbers.is_empty() {
+        grand_total += resolve_block(&block_numbers, block_op);
+    }
+
+    grand_total
+}
+
+fn resolve_block(numbers: &[u64], op: Option<u8>) -> u64 {
+    match op.unwrap() {
+        b'+' => numbers.iter().sum(),
+        b'*' => numbers.iter().product(),
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve_p1(EXAMPLE), 4277556);
+        assert_eq!(solve_p2(EXAMPLE), 3263827);
+    }
+
+    // --raw hands CRLF files over as they are
+    #[test]
+    fn crlf_part1() {
+        assert_eq!(solve_p1(&EXAMPLE.replace('\n', "\r\n")), 4277556);
+    }
+}
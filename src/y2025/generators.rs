@@ -0,0 +1,162 @@
+// Generators for the 2025 puzzles, see crate::generators
+use std::fmt::Write;
+
+use crate::generators::Rng;
+
+// What size means for each day, and what we use when none is given (roughly the real input)
+// 1: rotations  2: ranges  3: banks  4: grid side  5: ranges (and 5x as many ids)
+// 6: problems  7: manifold width  8: points
+pub fn default_size(day: u8) -> Option<usize> {
+    match day {
+        1 => Some(4000),
+        2 => Some(40),
+        3 => Some(200),
+        4 => Some(140),
+        5 => Some(200),
+        6 => Some(1000),
+        7 => Some(141),
+        8 => Some(1000),
+        _ => None,
+    }
+}
+
+pub fn generate(day: u8, size: usize, rng: &mut Rng) -> Option<String> {
+    let input = match day {
+        1 => rotations(rng, size),
+        2 => id_ranges(rng, size),
+        3 => banks(rng, size),
+        4 => paper_grid(rng, size),
+        5 => elf_db(rng, size),
+        6 => worksheet(rng, size),
+        7 => splitters(rng, size),
+        8 => points(rng, size),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+// L/R followed by a click count, big counts included so full turns get exercised
+fn rotations(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let direction = if rng.chance(50) { 'L' } else { 'R' };
+        writeln!(out, "{}{}", direction, 1 + rng.below(999)).unwrap();
+    }
+    out
+}
+
+// One line of comma separated a-b ranges, bounds up to 10 digits
+fn id_ranges(rng: &mut Rng, size: usize) -> String {
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = 1 + rng.below(10) as u32;
+            let start = rng.below(10u64.pow(digits));
+            let end = start + rng.below(200_000);
+            format!("{}-{}", start, end)
+        })
+        .collect();
+    ranges.join(",") + "\n"
+}
+
+// Banks of 100 digits (no zeros, like the real input)
+fn banks(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::with_capacity(size * 101);
+    for _ in 0..size {
+        for _ in 0..100 {
+            out.push((b'1' + rng.below(9) as u8) as char);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+// size x size grid roughly 60% paper rolls
+fn paper_grid(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::with_capacity(size * (size + 1));
+    for _ in 0..size {
+        for _ in 0..size {
+            out.push(if rng.chance(60) { '@' } else { '.' });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+// Overlapping fresh ranges, a blank line, then 5x as many ingredient ids
+fn elf_db(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let start = rng.below(500_000_000_000_000);
+        let end = start + rng.below(5_000_000_000_000);
+        writeln!(out, "{}-{}", start, end).unwrap();
+    }
+    out.push('\n');
+    for _ in 0..size * 5 {
+        writeln!(out, "{}", rng.below(505_000_000_000_000)).unwrap();
+    }
+    out
+}
+
+// Problems of 4 numbers each, aligned left or right within their block. Numbers are kept to 3
+// digits so a single product cant overflow
+fn worksheet(rng: &mut Rng, size: usize) -> String {
+    let rows = 4;
+    let mut lines = vec![String::new(); rows + 1];
+
+    for problem in 0..size {
+        let numbers: Vec<String> = (0..rows)
+            .map(|_| (1 + rng.below(999)).to_string())
+            .collect();
+        let width = numbers.iter().map(|n| n.len()).max().unwrap();
+        let left_aligned = rng.chance(50);
+
+        if problem > 0 {
+            lines.iter_mut().for_each(|l| l.push(' '));
+        }
+
+        for (line, number) in lines.iter_mut().zip(&numbers) {
+            if left_aligned {
+                write!(line, "{:<width$}", number).unwrap();
+            } else {
+                write!(line, "{:>width$}", number).unwrap();
+            }
+        }
+
+        let op = if rng.chance(50) { '+' } else { '*' };
+        write!(lines[rows], "{:<width$}", op).unwrap();
+    }
+
+    lines.join("\n") + "\n"
+}
+
+// size wide manifold (one row taller) with the start in the middle and splitters on every other
+// row. Timeline counts grow quickly, very large sizes can overflow part 2
+fn splitters(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::with_capacity(size * (size + 2));
+
+    for y in 0..=size {
+        for x in 0..size {
+            let c = if y == 0 && x == size / 2 {
+                'S'
+            } else if y > 0 && y % 2 == 0 && rng.chance(30) {
+                '^'
+            } else {
+                '.'
+            };
+            out.push(c);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+// Junction boxes in a 100k cube
+fn points(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let (x, y, z) = (rng.below(100_000), rng.below(100_000), rng.below(100_000));
+        writeln!(out, "{},{},{}", x, y, z).unwrap();
+    }
+    out
+}